@@ -1,9 +1,12 @@
+pub mod algorithms;
 pub mod connection;
 pub mod connections_backward;
 pub mod connections_forward;
+pub mod degree_limits;
 pub mod error;
 pub mod graph;
 pub mod graph_settings;
 pub mod graph_trait;
+pub mod neural;
 pub mod node;
 pub mod unsigned_int;
@@ -0,0 +1,16 @@
+pub mod centrality;
+pub mod cliques;
+pub mod clustering;
+pub mod communities;
+pub mod components;
+pub mod critical_path;
+pub mod cycles;
+pub mod flow;
+pub mod k_core;
+pub mod layers;
+pub mod page_rank;
+pub mod paths;
+pub mod pregel;
+pub mod reachability;
+pub(crate) mod shortest_paths;
+pub(crate) mod topological_sort;
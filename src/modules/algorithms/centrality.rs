@@ -0,0 +1,552 @@
+use crate::modules::algorithms::shortest_paths::ShortestPathDag;
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::graph_trait::Graph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::SeedableRng;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Which nodes the shortest path based centralities start their searches from.
+#[derive(Clone, Debug)]
+pub enum CentralitySources {
+    /// Every node. Exact, but costs one search per node.
+    All,
+    /// A random subset of `num_sources` nodes, picked with a seeded rng. The scores are scaled up
+    /// to estimate the exact values, which makes large graphs affordable. `num_sources` must be
+    /// at least 1.
+    Sampled { num_sources: usize, seed: u64 },
+}
+
+/// Closeness and harmonic centrality, indexed by node id. Empty slots are 0.
+///
+/// Both are measured on incoming distances: how close every other node is to this one.
+#[derive(Clone, Debug)]
+pub struct ClosenessCentrality {
+    /// Wasserman and Faust closeness: the inverse average distance from the nodes that can reach
+    /// this node, scaled by the fraction of the graph that can reach it.
+    pub closeness: Vec<f64>,
+    /// Sum of the inverse distances from every other node. Unreachable nodes add 0.
+    pub harmonic: Vec<f64>,
+}
+
+/// Settings for the power iteration based centralities.
+#[derive(Clone, Debug)]
+pub struct PowerIterationSettings {
+    /// Stop once the L1 change between two iterations drops below `tolerance` times the number of
+    /// nodes.
+    pub tolerance: f64,
+    /// Give up with `VeloxGraphError::DidNotConverge` after this many iterations.
+    pub max_iterations: usize,
+}
+
+impl Default for PowerIterationSettings {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-6,
+            max_iterations: 100,
+        }
+    }
+}
+
+/// HITS scores, indexed by node id. Empty slots are 0. Each list sums to 1.
+#[derive(Clone, Debug)]
+pub struct HitsScores {
+    /// High for nodes that connect forward to many good authorities ("broadcasters").
+    pub hubs: Vec<f64>,
+    /// High for nodes that many good hubs connect to ("integrators").
+    pub authorities: Vec<f64>,
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Compute betweenness centrality with Brandes' algorithm, counting every connection as 1 hop.
+    ///
+    /// The result is indexed by node id and holds, for each node, the number of shortest paths
+    /// between other node pairs that pass through it (split evenly between ties). Empty slots are 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::algorithms::centrality::CentralitySources;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Every path from node0 to node2 goes through node1.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id1, node_id2, 1.0).unwrap();
+    ///
+    /// let betweenness = graph.betweenness_centrality(&CentralitySources::All).unwrap();
+    ///
+    /// assert_eq!(betweenness, vec![0.0, 1.0, 0.0]);
+    /// ```
+    pub fn betweenness_centrality(
+        &self,
+        sources: &CentralitySources,
+    ) -> Result<Vec<f64>, VeloxGraphError> {
+        self.betweenness_run(sources, |source_node_id| {
+            self.shortest_path_dag_unweighted(source_node_id)
+        })
+    }
+
+    /// Compute betweenness centrality with Brandes' algorithm, using `cost_fn` as the length of each
+    /// connection.
+    ///
+    /// Costs must be positive, otherwise shortest paths tied through a zero cost connection
+    /// cannot all be counted. Fails with `InvalidArgument` if any cost is zero, negative or NaN.
+    /// Self connections are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::algorithms::centrality::CentralitySources;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: The direct connection is longer than the detour through node1.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// graph.nodes_connection_set(node_id0, node_id2, 5.0).unwrap();
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id1, node_id2, 1.0).unwrap();
+    ///
+    /// let betweenness = graph
+    ///     .betweenness_centrality_weighted(&CentralitySources::All, |cost| *cost)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(betweenness[node_id1], 1.0);
+    /// ```
+    pub fn betweenness_centrality_weighted<CostFn>(
+        &self,
+        sources: &CentralitySources,
+        cost_fn: CostFn,
+    ) -> Result<Vec<f64>, VeloxGraphError>
+    where
+        CostFn: Fn(&ConnectionDataT) -> f64,
+    {
+        self.costs_check(&cost_fn, false)?;
+
+        self.betweenness_run(sources, |source_node_id| {
+            self.shortest_path_dag_weighted(source_node_id, &cost_fn)
+        })
+    }
+
+    /// Compute closeness and harmonic centrality, counting every connection as 1 hop.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::algorithms::centrality::CentralitySources;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: node0 -> node1 -> node2.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id1, node_id2, 1.0).unwrap();
+    ///
+    /// let centrality = graph.closeness_centrality(&CentralitySources::All).unwrap();
+    ///
+    /// assert_eq!(centrality.harmonic, vec![0.0, 1.0, 1.5]);
+    /// ```
+    pub fn closeness_centrality(
+        &self,
+        sources: &CentralitySources,
+    ) -> Result<ClosenessCentrality, VeloxGraphError> {
+        self.closeness_run(sources, |source_node_id| {
+            self.shortest_path_dag_unweighted(source_node_id)
+        })
+    }
+
+    /// Compute closeness and harmonic centrality, using `cost_fn` as the length of each
+    /// connection.
+    ///
+    /// Costs must be positive, as for `betweenness_centrality_weighted`. Fails with
+    /// `InvalidArgument` if any cost is zero, negative or NaN. Self connections are ignored.
+    pub fn closeness_centrality_weighted<CostFn>(
+        &self,
+        sources: &CentralitySources,
+        cost_fn: CostFn,
+    ) -> Result<ClosenessCentrality, VeloxGraphError>
+    where
+        CostFn: Fn(&ConnectionDataT) -> f64,
+    {
+        self.costs_check(&cost_fn, false)?;
+
+        self.closeness_run(sources, |source_node_id| {
+            self.shortest_path_dag_weighted(source_node_id, &cost_fn)
+        })
+    }
+
+    /// Source nodes to search from, and the factor that scales sampled sums up to full sums.
+    /// Fails with `InvalidArgument` when sampling 0 sources.
+    fn centrality_sources(
+        &self,
+        sources: &CentralitySources,
+    ) -> Result<(Vec<usize>, f64), VeloxGraphError> {
+        let node_ids: Vec<usize> = self.node_ids().collect();
+
+        match sources {
+            CentralitySources::Sampled { num_sources: 0, .. } => Err(
+                VeloxGraphError::InvalidArgument("cannot sample 0 source nodes".to_string()),
+            ),
+            CentralitySources::Sampled { num_sources, seed } if *num_sources < node_ids.len() => {
+                let mut rng = StdRng::seed_from_u64(*seed);
+                let sampled_node_ids: Vec<usize> = node_ids
+                    .choose_multiple(&mut rng, *num_sources)
+                    .copied()
+                    .collect();
+                let scale = node_ids.len() as f64 / *num_sources as f64;
+
+                Ok((sampled_node_ids, scale))
+            }
+            _ => Ok((node_ids, 1.0)),
+        }
+    }
+
+    fn betweenness_run<DagFn>(
+        &self,
+        sources: &CentralitySources,
+        dag_fn: DagFn,
+    ) -> Result<Vec<f64>, VeloxGraphError>
+    where
+        DagFn: Fn(usize) -> ShortestPathDag,
+    {
+        let (source_node_ids, scale) = self.centrality_sources(sources)?;
+        let mut betweenness = vec![0.0; self.nodes_vector.len()];
+        let mut dependencies = vec![0.0; self.nodes_vector.len()];
+
+        for source_node_id in source_node_ids {
+            let dag = dag_fn(source_node_id);
+
+            // INFO: accumulate dependencies from the furthest nodes back towards the source.
+            for &node_id in dag.order.iter().rev() {
+                let coefficient = (1.0 + dependencies[node_id]) / dag.path_counts[node_id];
+                for &predecessor_id in &dag.predecessors[node_id] {
+                    dependencies[predecessor_id] += dag.path_counts[predecessor_id] * coefficient;
+                }
+
+                if node_id != source_node_id {
+                    betweenness[node_id] += dependencies[node_id] * scale;
+                }
+            }
+
+            for &node_id in &dag.order {
+                dependencies[node_id] = 0.0;
+            }
+        }
+
+        Ok(betweenness)
+    }
+
+    fn closeness_run<DagFn>(
+        &self,
+        sources: &CentralitySources,
+        dag_fn: DagFn,
+    ) -> Result<ClosenessCentrality, VeloxGraphError>
+    where
+        DagFn: Fn(usize) -> ShortestPathDag,
+    {
+        let (source_node_ids, scale) = self.centrality_sources(sources)?;
+        let num_slots = self.nodes_vector.len();
+        let mut distance_totals = vec![0.0; num_slots];
+        let mut reach_counts = vec![0.0; num_slots];
+        let mut harmonic = vec![0.0; num_slots];
+
+        for source_node_id in source_node_ids {
+            let dag = dag_fn(source_node_id);
+
+            for &node_id in &dag.order {
+                let distance = dag.distances[node_id];
+                if node_id == source_node_id {
+                    continue;
+                }
+
+                distance_totals[node_id] += distance * scale;
+                reach_counts[node_id] += scale;
+                harmonic[node_id] += scale / distance;
+            }
+        }
+
+        let num_other_nodes = self.num_entries().saturating_sub(1) as f64;
+        let mut closeness = vec![0.0; num_slots];
+        for node_id in self.node_ids() {
+            if distance_totals[node_id] > 0.0 {
+                let reach_count = reach_counts[node_id].min(num_other_nodes);
+                closeness[node_id] =
+                    (reach_count / distance_totals[node_id]) * (reach_count / num_other_nodes);
+            }
+        }
+
+        Ok(ClosenessCentrality {
+            closeness,
+            harmonic,
+        })
+    }
+
+    /// Compute eigenvector centrality over forward connections, weighted with `weight_fn`.
+    ///
+    /// A node is central when central nodes connect forward to it. The result is indexed by node
+    /// id, has an L2 norm of 1, and empty slots are 0. Weights should be non-negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::algorithms::centrality::PowerIterationSettings;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: A cycle where node2 also receives a connection from node0.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id1, node_id2, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id2, node_id0, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id0, node_id2, 1.0).unwrap();
+    ///
+    /// let centrality = graph
+    ///     .eigenvector_centrality(&PowerIterationSettings::default(), |weight| *weight)
+    ///     .unwrap();
+    ///
+    /// assert!(centrality[node_id2] > centrality[node_id1]);
+    /// ```
+    pub fn eigenvector_centrality<WeightFn>(
+        &self,
+        settings: &PowerIterationSettings,
+        weight_fn: WeightFn,
+    ) -> Result<Vec<f64>, VeloxGraphError>
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        let num_nodes = self.num_entries();
+        let mut scores = vec![0.0; self.nodes_vector.len()];
+        for node_id in self.node_ids() {
+            scores[node_id] = 1.0 / num_nodes as f64;
+        }
+
+        for _iteration in 0..settings.max_iterations {
+            // INFO: iterate with (A + I) instead of A, so that periodic graphs still converge.
+            let mut next_scores = scores.clone();
+            for (node_id, node) in self.nodes() {
+                for connection in node.connections_forward.data() {
+                    next_scores[connection.node_id()] +=
+                        weight_fn(&connection.data) * scores[node_id];
+                }
+            }
+
+            let norm = next_scores
+                .iter()
+                .map(|score| score * score)
+                .sum::<f64>()
+                .sqrt();
+            if norm == 0.0 {
+                return Ok(next_scores);
+            }
+            next_scores.iter_mut().for_each(|score| *score /= norm);
+
+            let change = l1_distance(&scores, &next_scores);
+            scores = next_scores;
+            if change < settings.tolerance * num_nodes as f64 {
+                return Ok(scores);
+            }
+        }
+
+        Err(VeloxGraphError::DidNotConverge(settings.max_iterations))
+    }
+
+    /// Compute Katz centrality over forward connections, weighted with `weight_fn`.
+    ///
+    /// Solves `x = alpha * A^T x + beta`, so every node gets `beta` for free plus `alpha` times the
+    /// weighted score of each node connecting forward to it. `alpha` must be smaller than
+    /// 1 / (largest eigenvalue of the weighted adjacency matrix), otherwise the iteration diverges.
+    /// Scores are not normalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::algorithms::centrality::PowerIterationSettings;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: node0 -> node1.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    ///
+    /// let centrality = graph
+    ///     .katz_centrality(&PowerIterationSettings::default(), 0.1, 1.0, |weight| *weight)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(centrality, vec![1.0, 1.1]);
+    /// ```
+    pub fn katz_centrality<WeightFn>(
+        &self,
+        settings: &PowerIterationSettings,
+        alpha: f64,
+        beta: f64,
+        weight_fn: WeightFn,
+    ) -> Result<Vec<f64>, VeloxGraphError>
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        let num_nodes = self.num_entries();
+        let mut scores = vec![0.0; self.nodes_vector.len()];
+        if num_nodes == 0 {
+            return Ok(scores);
+        }
+
+        for _iteration in 0..settings.max_iterations {
+            let mut next_scores = vec![0.0; self.nodes_vector.len()];
+            for node_id in self.node_ids() {
+                next_scores[node_id] = beta;
+            }
+
+            for (node_id, node) in self.nodes() {
+                for connection in node.connections_forward.data() {
+                    next_scores[connection.node_id()] +=
+                        alpha * weight_fn(&connection.data) * scores[node_id];
+                }
+            }
+
+            let change = l1_distance(&scores, &next_scores);
+            scores = next_scores;
+            if change < settings.tolerance * num_nodes as f64 {
+                return Ok(scores);
+            }
+        }
+
+        Err(VeloxGraphError::DidNotConverge(settings.max_iterations))
+    }
+
+    /// Compute HITS hub and authority scores, weighted with `weight_fn`.
+    ///
+    /// Authorities are collected along forward connections from the hubs, and hubs along the same
+    /// connections from the authorities. Weights should be non-negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::algorithms::centrality::PowerIterationSettings;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: node0 broadcasts to node1 and node2.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id0, node_id2, 1.0).unwrap();
+    ///
+    /// let scores = graph
+    ///     .hits(&PowerIterationSettings::default(), |weight| *weight)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(scores.hubs, vec![1.0, 0.0, 0.0]);
+    /// assert_eq!(scores.authorities, vec![0.0, 0.5, 0.5]);
+    /// ```
+    pub fn hits<WeightFn>(
+        &self,
+        settings: &PowerIterationSettings,
+        weight_fn: WeightFn,
+    ) -> Result<HitsScores, VeloxGraphError>
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        let num_nodes = self.num_entries();
+        if num_nodes == 0 {
+            return Ok(HitsScores {
+                hubs: vec![0.0; self.nodes_vector.len()],
+                authorities: vec![0.0; self.nodes_vector.len()],
+            });
+        }
+        let mut hubs = vec![0.0; self.nodes_vector.len()];
+        for node_id in self.node_ids() {
+            hubs[node_id] = 1.0 / num_nodes as f64;
+        }
+        let mut authorities = vec![0.0; self.nodes_vector.len()];
+
+        for _iteration in 0..settings.max_iterations {
+            authorities.iter_mut().for_each(|score| *score = 0.0);
+            for (node_id, node) in self.nodes() {
+                for connection in node.connections_forward.data() {
+                    authorities[connection.node_id()] +=
+                        weight_fn(&connection.data) * hubs[node_id];
+                }
+            }
+            normalize_sum(&mut authorities);
+
+            let mut next_hubs = vec![0.0; self.nodes_vector.len()];
+            for (node_id, node) in self.nodes() {
+                next_hubs[node_id] = node
+                    .connections_forward
+                    .data()
+                    .iter()
+                    .map(|connection| {
+                        weight_fn(&connection.data) * authorities[connection.node_id()]
+                    })
+                    .sum();
+            }
+            normalize_sum(&mut next_hubs);
+
+            let change = l1_distance(&hubs, &next_hubs);
+            hubs = next_hubs;
+            if change < settings.tolerance * num_nodes as f64 {
+                return Ok(HitsScores { hubs, authorities });
+            }
+        }
+
+        Err(VeloxGraphError::DidNotConverge(settings.max_iterations))
+    }
+}
+
+fn l1_distance(first: &[f64], second: &[f64]) -> f64 {
+    first
+        .iter()
+        .zip(second)
+        .map(|(first_value, second_value)| (first_value - second_value).abs())
+        .sum()
+}
+
+fn normalize_sum(values: &mut [f64]) {
+    let total: f64 = values.iter().sum();
+    if total != 0.0 {
+        values.iter_mut().for_each(|value| *value /= total);
+    }
+}
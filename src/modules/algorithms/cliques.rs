@@ -0,0 +1,193 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Collect every maximal clique with at least `min_size` nodes.
+    ///
+    /// Connection direction is ignored: two nodes are neighbors if either connects to the other.
+    /// Each clique is a list of node ids in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: A triangle (0, 1, 2) with a tail node3 hanging off node2.
+    /// for index in 0..4 {
+    ///     graph.node_create(index);
+    /// }
+    /// for (first, second) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+    ///     graph.nodes_connection_set(first, second, 1.0).unwrap();
+    /// }
+    ///
+    /// assert_eq!(graph.maximal_cliques(3), vec![vec![0, 1, 2]]);
+    /// ```
+    pub fn maximal_cliques(&self, min_size: usize) -> Vec<Vec<usize>> {
+        let mut cliques = Vec::new();
+        self.maximal_cliques_for_each(min_size, |clique| cliques.push(clique.to_vec()));
+
+        cliques.sort_unstable();
+        cliques
+    }
+
+    /// Call `clique_fn` with every maximal clique that has at least `min_size` nodes.
+    ///
+    /// Uses Bron-Kerbosch with pivoting and streams the cliques as they are found, so they are
+    /// never all held in memory at once. Each clique is passed as node ids in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Two triangles sharing node2.
+    /// for index in 0..5 {
+    ///     graph.node_create(index);
+    /// }
+    /// for (first, second) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)] {
+    ///     graph.nodes_connection_set(first, second, 1.0).unwrap();
+    /// }
+    ///
+    /// let mut num_cliques = 0;
+    /// graph.maximal_cliques_for_each(3, |clique| {
+    ///     assert!(clique.contains(&2));
+    ///     num_cliques += 1;
+    /// });
+    ///
+    /// assert_eq!(num_cliques, 2);
+    /// ```
+    pub fn maximal_cliques_for_each<CliqueFn>(&self, min_size: usize, mut clique_fn: CliqueFn)
+    where
+        CliqueFn: FnMut(&[usize]),
+    {
+        let mut neighbors = vec![Vec::new(); self.nodes_vector.len()];
+        for node_id in self.node_ids() {
+            neighbors[node_id] = self
+                .undirected_neighbors(node_id)
+                .into_iter()
+                .map(|(neighbor_id, _)| neighbor_id)
+                .collect();
+        }
+
+        let candidates: Vec<usize> = self.node_ids().collect();
+        let mut clique = Vec::new();
+        let mut sorted_clique = Vec::new();
+        bron_kerbosch(
+            &neighbors,
+            min_size,
+            &mut clique,
+            candidates,
+            Vec::new(),
+            &mut |clique: &[usize]| {
+                sorted_clique.clear();
+                sorted_clique.extend_from_slice(clique);
+                sorted_clique.sort_unstable();
+                clique_fn(&sorted_clique);
+            },
+        );
+    }
+}
+
+/// Bron-Kerbosch with Tomita pivoting. `candidates` and `excluded` are sorted node ids.
+fn bron_kerbosch<CliqueFn>(
+    neighbors: &[Vec<usize>],
+    min_size: usize,
+    clique: &mut Vec<usize>,
+    mut candidates: Vec<usize>,
+    mut excluded: Vec<usize>,
+    clique_fn: &mut CliqueFn,
+) where
+    CliqueFn: FnMut(&[usize]),
+{
+    if candidates.is_empty() {
+        if excluded.is_empty() && !clique.is_empty() && clique.len() >= min_size {
+            clique_fn(clique);
+        }
+        return;
+    }
+
+    if clique.len() + candidates.len() < min_size {
+        return;
+    }
+
+    // INFO: pivot on the node covering the most candidates, and skip the pivot's neighbors.
+    let pivot_id = candidates
+        .iter()
+        .chain(excluded.iter())
+        .copied()
+        .max_by_key(|&node_id| intersect_count(&candidates, &neighbors[node_id]))
+        .unwrap();
+    let branch_node_ids: Vec<usize> = candidates
+        .iter()
+        .copied()
+        .filter(|node_id| neighbors[pivot_id].binary_search(node_id).is_err())
+        .collect();
+
+    for node_id in branch_node_ids {
+        clique.push(node_id);
+        bron_kerbosch(
+            neighbors,
+            min_size,
+            clique,
+            intersect(&candidates, &neighbors[node_id]),
+            intersect(&excluded, &neighbors[node_id]),
+            clique_fn,
+        );
+        clique.pop();
+
+        if let Ok(index) = candidates.binary_search(&node_id) {
+            candidates.remove(index);
+        }
+        if let Err(index) = excluded.binary_search(&node_id) {
+            excluded.insert(index, node_id);
+        }
+    }
+}
+
+fn intersect(first: &[usize], second: &[usize]) -> Vec<usize> {
+    let mut common = Vec::new();
+    let mut first_index = 0;
+    let mut second_index = 0;
+
+    while first_index < first.len() && second_index < second.len() {
+        match first[first_index].cmp(&second[second_index]) {
+            std::cmp::Ordering::Less => first_index += 1,
+            std::cmp::Ordering::Greater => second_index += 1,
+            std::cmp::Ordering::Equal => {
+                common.push(first[first_index]);
+                first_index += 1;
+                second_index += 1;
+            }
+        }
+    }
+
+    common
+}
+
+fn intersect_count(first: &[usize], second: &[usize]) -> usize {
+    second
+        .iter()
+        .filter(|node_id| first.binary_search(node_id).is_ok())
+        .count()
+}
@@ -0,0 +1,235 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Per node triangle tallies, before any normalization.
+struct TriangleTallies {
+    neighbors: Vec<Vec<(usize, u8)>>,
+    /// Twice the number of undirected triangles through each node.
+    undirected: Vec<usize>,
+    /// Twice the number of directed triangles through each node (Fagiolo).
+    directed: Vec<usize>,
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Count the triangles through each node, ignoring connection direction.
+    ///
+    /// The result is indexed by node id and empty slots are 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphHash;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphHash<usize, u32, f64> = VeloxGraphHash::new();
+    ///
+    /// // INFO: One triangle (0, 1, 2) and a tail node3.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// let node_id3 = graph.node_create(3);
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id1, node_id2, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id0, node_id2, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id2, node_id3, 1.0).unwrap();
+    ///
+    /// assert_eq!(graph.triangles(), vec![1, 1, 1, 0]);
+    /// assert_eq!(graph.triangle_count(), 1);
+    /// ```
+    pub fn triangles(&self) -> Vec<usize> {
+        let tallies = self.triangle_tallies();
+
+        tallies.undirected.iter().map(|count| count / 2).collect()
+    }
+
+    /// Count the triangles in the whole graph, ignoring connection direction.
+    pub fn triangle_count(&self) -> usize {
+        let tallies = self.triangle_tallies();
+
+        tallies.undirected.iter().sum::<usize>() / 6
+    }
+
+    /// Count the directed triangles through each node.
+    ///
+    /// Every combination of connection directions between the three nodes counts, so a triangle
+    /// that is connected both ways on all three sides counts 8 times. This is the numerator of
+    /// Fagiolo's directed clustering coefficient.
+    pub fn triangles_directed(&self) -> Vec<usize> {
+        let tallies = self.triangle_tallies();
+
+        tallies.directed.iter().map(|count| count / 2).collect()
+    }
+
+    /// Compute the local clustering coefficient of each node, ignoring connection direction.
+    ///
+    /// This is the fraction of pairs of neighbors that are themselves connected. Nodes with fewer
+    /// than two neighbors, and empty slots, are 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: One triangle (0, 1, 2) and a tail node3.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// let node_id3 = graph.node_create(3);
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id1, node_id2, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id0, node_id2, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id2, node_id3, 1.0).unwrap();
+    ///
+    /// let clustering = graph.clustering_coefficients();
+    ///
+    /// assert_eq!(clustering[node_id0], 1.0);
+    /// assert!((clustering[node_id2] - 1.0 / 3.0).abs() < 1e-12);
+    /// ```
+    pub fn clustering_coefficients(&self) -> Vec<f64> {
+        let tallies = self.triangle_tallies();
+
+        let mut clustering = vec![0.0; self.nodes_vector.len()];
+        for node_id in self.node_ids() {
+            let degree = tallies.neighbors[node_id].len();
+            if degree >= 2 {
+                clustering[node_id] =
+                    tallies.undirected[node_id] as f64 / (degree * (degree - 1)) as f64;
+            }
+        }
+
+        clustering
+    }
+
+    /// Compute Fagiolo's directed local clustering coefficient of each node.
+    ///
+    /// Nodes that cannot be part of a directed triangle, and empty slots, are 0.
+    pub fn clustering_coefficients_directed(&self) -> Vec<f64> {
+        let tallies = self.triangle_tallies();
+
+        let mut clustering = vec![0.0; self.nodes_vector.len()];
+        for node_id in self.node_ids() {
+            let neighbors = &tallies.neighbors[node_id];
+            let total_degree: usize = neighbors
+                .iter()
+                .map(|&(_, multiplicity)| multiplicity as usize)
+                .sum();
+            let num_bidirectional = neighbors
+                .iter()
+                .filter(|&&(_, multiplicity)| multiplicity == 2)
+                .count();
+
+            let possible = total_degree * total_degree.saturating_sub(1) - 2 * num_bidirectional;
+            if possible > 0 {
+                clustering[node_id] = tallies.directed[node_id] as f64 / (2 * possible) as f64;
+            }
+        }
+
+        clustering
+    }
+
+    /// Compute the global transitivity, ignoring connection direction.
+    ///
+    /// This is three times the number of triangles divided by the number of connected triples.
+    pub fn transitivity(&self) -> f64 {
+        let tallies = self.triangle_tallies();
+
+        let twice_triangles: usize = tallies.undirected.iter().sum();
+        let twice_triples: usize = tallies
+            .neighbors
+            .iter()
+            .map(|neighbors| neighbors.len() * neighbors.len().saturating_sub(1))
+            .sum();
+
+        match twice_triples {
+            0 => 0.0,
+            _ => twice_triangles as f64 / twice_triples as f64,
+        }
+    }
+
+    fn triangle_tallies(&self) -> TriangleTallies {
+        let num_slots = self.nodes_vector.len();
+        let mut neighbors = vec![Vec::new(); num_slots];
+        for node_id in self.node_ids() {
+            neighbors[node_id] = self.undirected_neighbors(node_id);
+        }
+
+        let mut undirected = vec![0; num_slots];
+        let mut directed = vec![0; num_slots];
+        let mut common = Vec::new();
+
+        for node_id in self.node_ids() {
+            for &(neighbor_id, node_multiplicity) in &neighbors[node_id] {
+                common.clear();
+
+                if ConnForwardT::CONSTANT_TIME_CONTAINS {
+                    // INFO: hash backend: check every other neighbor with the lookup hash.
+                    for &(other_id, other_multiplicity) in &neighbors[node_id] {
+                        if other_id == neighbor_id {
+                            continue;
+                        }
+
+                        let shared_multiplicity = self.has_connection(neighbor_id, other_id) as u8
+                            + self.has_connection(other_id, neighbor_id) as u8;
+                        if shared_multiplicity > 0 {
+                            common.push((other_multiplicity, shared_multiplicity));
+                        }
+                    }
+                } else {
+                    // INFO: vec backend: intersect the two sorted neighbor lists.
+                    intersect_sorted(&neighbors[node_id], &neighbors[neighbor_id], &mut common);
+                }
+
+                for &(other_multiplicity, shared_multiplicity) in &common {
+                    undirected[node_id] += 1;
+                    directed[node_id] +=
+                        (node_multiplicity * other_multiplicity * shared_multiplicity) as usize;
+                }
+            }
+        }
+
+        TriangleTallies {
+            neighbors,
+            undirected,
+            directed,
+        }
+    }
+}
+
+/// Push the multiplicities (in `first`, in `second`) of every node id in both sorted lists.
+fn intersect_sorted(first: &[(usize, u8)], second: &[(usize, u8)], common: &mut Vec<(u8, u8)>) {
+    let mut first_index = 0;
+    let mut second_index = 0;
+
+    while first_index < first.len() && second_index < second.len() {
+        let (first_id, first_multiplicity) = first[first_index];
+        let (second_id, second_multiplicity) = second[second_index];
+
+        match first_id.cmp(&second_id) {
+            std::cmp::Ordering::Less => first_index += 1,
+            std::cmp::Ordering::Greater => second_index += 1,
+            std::cmp::Ordering::Equal => {
+                common.push((first_multiplicity, second_multiplicity));
+                first_index += 1;
+                second_index += 1;
+            }
+        }
+    }
+}
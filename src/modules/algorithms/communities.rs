@@ -0,0 +1,408 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::SeedableRng;
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::HashMap;
+
+const LABEL_PROPAGATION_MAX_SWEEPS: usize = 100;
+const LOUVAIN_MIN_GAIN: f64 = 1e-12;
+
+/// Result of a community detection run.
+#[derive(Clone, Debug)]
+pub struct Communities {
+    /// Community of each node, indexed by node id. `None` for empty slots. Communities are
+    /// numbered from 0 in order of their smallest node id.
+    pub assignments: Vec<Option<usize>>,
+    pub num_communities: usize,
+    /// Modularity of `assignments` on the undirected, weighted graph.
+    pub modularity: f64,
+}
+
+/// Undirected weighted graph over compact indices, as used by Louvain's aggregation steps.
+struct WeightedGraph {
+    /// Neighbors and summed weights (both directions), excluding self connections.
+    adjacency: Vec<Vec<(usize, f64)>>,
+    /// Total weight of the self connections of each node.
+    self_loops: Vec<f64>,
+}
+
+impl WeightedGraph {
+    fn degree(&self, index: usize) -> f64 {
+        self.adjacency[index]
+            .iter()
+            .map(|&(_, weight)| weight)
+            .sum::<f64>()
+            + 2.0 * self.self_loops[index]
+    }
+
+    fn from_buckets(buckets: Vec<HashMap<usize, f64>>, self_loops: Vec<f64>) -> Self {
+        let adjacency = buckets
+            .into_iter()
+            .map(|bucket| {
+                let mut neighbors: Vec<(usize, f64)> = bucket.into_iter().collect();
+                neighbors.sort_unstable_by_key(|&(index, _)| index);
+                neighbors
+            })
+            .collect();
+
+        Self {
+            adjacency,
+            self_loops,
+        }
+    }
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Detect communities with asynchronous label propagation.
+    ///
+    /// Every node starts in its own community and repeatedly adopts the label with the largest
+    /// total connection weight among its neighbors (in both directions). Nodes are visited in a
+    /// random order drawn from `seed`, so the same seed always gives the same result. Weights
+    /// should be non-negative; use `|_| 1.0` for an unweighted run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Two separate triangles.
+    /// for index in 0..6 {
+    ///     graph.node_create(index);
+    /// }
+    /// for (first, second) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+    ///     graph.nodes_connection_set(first, second, 1.0).unwrap();
+    /// }
+    ///
+    /// let communities = graph.label_propagation(7, |_| 1.0);
+    ///
+    /// assert_eq!(communities.num_communities, 2);
+    /// assert_eq!(communities.assignments[0], communities.assignments[2]);
+    /// assert_ne!(communities.assignments[0], communities.assignments[3]);
+    /// ```
+    pub fn label_propagation<WeightFn>(&self, seed: u64, weight_fn: WeightFn) -> Communities
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        let (node_ids, weighted_graph) = self.weighted_graph(&weight_fn);
+        let num_nodes = node_ids.len();
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut labels: Vec<usize> = (0..num_nodes).collect();
+        let mut order: Vec<usize> = (0..num_nodes).collect();
+        let mut label_weights = vec![0.0; num_nodes];
+        let mut touched_labels = Vec::new();
+        let mut best_labels = Vec::new();
+
+        for _sweep in 0..LABEL_PROPAGATION_MAX_SWEEPS {
+            order.shuffle(&mut rng);
+            let mut changed = false;
+
+            for &index in &order {
+                for &(neighbor_index, weight) in &weighted_graph.adjacency[index] {
+                    let label = labels[neighbor_index];
+                    if label_weights[label] == 0.0 {
+                        touched_labels.push(label);
+                    }
+                    label_weights[label] += weight;
+                }
+
+                let best_weight = touched_labels
+                    .iter()
+                    .map(|&label| label_weights[label])
+                    .fold(0.0, f64::max);
+                best_labels.clear();
+                best_labels.extend(
+                    touched_labels
+                        .iter()
+                        .copied()
+                        .filter(|&label| label_weights[label] == best_weight),
+                );
+
+                if best_weight > 0.0 && !best_labels.contains(&labels[index]) {
+                    best_labels.sort_unstable();
+                    labels[index] = *best_labels.choose(&mut rng).unwrap();
+                    changed = true;
+                }
+
+                for &label in &touched_labels {
+                    label_weights[label] = 0.0;
+                }
+                touched_labels.clear();
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        self.communities_from_labels(&node_ids, &labels, &weight_fn)
+    }
+
+    /// Detect communities with the Louvain method.
+    ///
+    /// Greedily moves single nodes between communities while modularity improves, then merges
+    /// each community into one node and repeats on the smaller graph until nothing moves.
+    /// Connections are treated as undirected, with the weights of both directions added up.
+    /// Weights should be non-negative; use `|_| 1.0` for an unweighted run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Two triangles joined by one weak connection.
+    /// for index in 0..6 {
+    ///     graph.node_create(index);
+    /// }
+    /// for (first, second) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+    ///     graph.nodes_connection_set(first, second, 1.0).unwrap();
+    /// }
+    /// graph.nodes_connection_set(2, 3, 0.1).unwrap();
+    ///
+    /// let communities = graph.louvain(|weight| *weight);
+    ///
+    /// assert_eq!(communities.num_communities, 2);
+    /// assert!(communities.modularity > 0.4);
+    /// ```
+    pub fn louvain<WeightFn>(&self, weight_fn: WeightFn) -> Communities
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        let (node_ids, mut weighted_graph) = self.weighted_graph(&weight_fn);
+
+        // INFO: community of each original node, as an index into the current aggregated graph.
+        let mut memberships: Vec<usize> = (0..node_ids.len()).collect();
+
+        loop {
+            let num_nodes = weighted_graph.adjacency.len();
+            let degrees: Vec<f64> = (0..num_nodes)
+                .map(|index| weighted_graph.degree(index))
+                .collect();
+            let total_degree: f64 = degrees.iter().sum();
+            if total_degree <= 0.0 {
+                break;
+            }
+
+            // INFO: phase 1: move single nodes to the neighboring community with the best gain.
+            let mut communities: Vec<usize> = (0..num_nodes).collect();
+            let mut community_degrees = degrees.clone();
+            let mut community_weights = vec![0.0; num_nodes];
+            let mut touched_communities = Vec::new();
+            let mut any_moved = false;
+
+            loop {
+                let mut moved = false;
+
+                for index in 0..num_nodes {
+                    let current_community = communities[index];
+                    touched_communities.push(current_community);
+                    for &(neighbor_index, weight) in &weighted_graph.adjacency[index] {
+                        let community = communities[neighbor_index];
+                        if community_weights[community] == 0.0 {
+                            touched_communities.push(community);
+                        }
+                        community_weights[community] += weight;
+                    }
+
+                    community_degrees[current_community] -= degrees[index];
+                    let gain = |community: usize| {
+                        community_weights[community]
+                            - community_degrees[community] * degrees[index] / total_degree
+                    };
+
+                    let mut best_community = current_community;
+                    let mut best_gain = gain(current_community);
+                    for &community in &touched_communities {
+                        let community_gain = gain(community);
+                        if community_gain > best_gain + LOUVAIN_MIN_GAIN {
+                            best_community = community;
+                            best_gain = community_gain;
+                        }
+                    }
+
+                    community_degrees[best_community] += degrees[index];
+                    if best_community != current_community {
+                        communities[index] = best_community;
+                        moved = true;
+                    }
+
+                    for &community in &touched_communities {
+                        community_weights[community] = 0.0;
+                    }
+                    touched_communities.clear();
+                }
+
+                if !moved {
+                    break;
+                }
+                any_moved = true;
+            }
+
+            if !any_moved {
+                break;
+            }
+
+            // INFO: phase 2: merge every community into a single node.
+            let mut renumbered = vec![usize::MAX; num_nodes];
+            let mut num_communities = 0;
+            for community in communities.iter_mut() {
+                if renumbered[*community] == usize::MAX {
+                    renumbered[*community] = num_communities;
+                    num_communities += 1;
+                }
+                *community = renumbered[*community];
+            }
+
+            let mut buckets = vec![HashMap::new(); num_communities];
+            let mut self_loops = vec![0.0; num_communities];
+            for index in 0..num_nodes {
+                let community = communities[index];
+                self_loops[community] += weighted_graph.self_loops[index];
+
+                for &(neighbor_index, weight) in &weighted_graph.adjacency[index] {
+                    let neighbor_community = communities[neighbor_index];
+                    if neighbor_community == community {
+                        // INFO: each internal connection is seen from both ends.
+                        self_loops[community] += weight / 2.0;
+                    } else {
+                        *buckets[community].entry(neighbor_community).or_insert(0.0) += weight;
+                    }
+                }
+            }
+
+            for membership in memberships.iter_mut() {
+                *membership = communities[*membership];
+            }
+            weighted_graph = WeightedGraph::from_buckets(buckets, self_loops);
+        }
+
+        self.communities_from_labels(&node_ids, &memberships, &weight_fn)
+    }
+
+    /// Compute the modularity of a community assignment, indexed by node id.
+    ///
+    /// Connections are treated as undirected, with the weights of both directions added up.
+    /// Nodes with a `None` assignment are treated as being in a community of their own.
+    pub fn modularity<WeightFn>(&self, assignments: &[Option<usize>], weight_fn: WeightFn) -> f64
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        // INFO: unassigned nodes get a community of their own, keyed by their node id.
+        let community_key = |node_id: usize| match assignments.get(node_id).copied().flatten() {
+            Some(community) => (true, community),
+            None => (false, node_id),
+        };
+
+        let mut total_weight = 0.0;
+        let mut internal_weights: HashMap<(bool, usize), f64> = HashMap::new();
+        let mut community_degrees: HashMap<(bool, usize), f64> = HashMap::new();
+
+        for (node_id, node) in self.nodes() {
+            let key = community_key(node_id);
+
+            for connection in node.connections_forward.data() {
+                let weight = weight_fn(&connection.data);
+                let connection_key = community_key(connection.node_id());
+                total_weight += weight;
+
+                *community_degrees.entry(key).or_insert(0.0) += weight;
+                *community_degrees.entry(connection_key).or_insert(0.0) += weight;
+                if key == connection_key {
+                    *internal_weights.entry(key).or_insert(0.0) += weight;
+                }
+            }
+        }
+
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+
+        let internal: f64 = internal_weights.values().sum::<f64>() / total_weight;
+        let expected: f64 = community_degrees
+            .values()
+            .map(|degree| (degree / (2.0 * total_weight)).powi(2))
+            .sum();
+
+        internal - expected
+    }
+
+    /// Collapse the graph to an undirected weighted graph over compact indices.
+    fn weighted_graph<WeightFn>(&self, weight_fn: &WeightFn) -> (Vec<usize>, WeightedGraph)
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        let node_ids: Vec<usize> = self.node_ids().collect();
+        let mut compact_indices = vec![usize::MAX; self.nodes_vector.len()];
+        for (index, &node_id) in node_ids.iter().enumerate() {
+            compact_indices[node_id] = index;
+        }
+
+        let mut buckets = vec![HashMap::new(); node_ids.len()];
+        let mut self_loops = vec![0.0; node_ids.len()];
+        for (node_id, node) in self.nodes() {
+            let index = compact_indices[node_id];
+            for connection in node.connections_forward.data() {
+                let weight = weight_fn(&connection.data);
+                let connection_index = compact_indices[connection.node_id()];
+
+                if connection_index == index {
+                    self_loops[index] += weight;
+                } else {
+                    *buckets[index].entry(connection_index).or_insert(0.0) += weight;
+                    *buckets[connection_index].entry(index).or_insert(0.0) += weight;
+                }
+            }
+        }
+
+        (node_ids, WeightedGraph::from_buckets(buckets, self_loops))
+    }
+
+    /// Renumber compact labels by smallest node id and score them.
+    fn communities_from_labels<WeightFn>(
+        &self,
+        node_ids: &[usize],
+        labels: &[usize],
+        weight_fn: &WeightFn,
+    ) -> Communities
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        let mut renumbered: HashMap<usize, usize> = HashMap::new();
+        let mut assignments = vec![None; self.nodes_vector.len()];
+        for (&node_id, &label) in node_ids.iter().zip(labels) {
+            let num_communities = renumbered.len();
+            let community = *renumbered.entry(label).or_insert(num_communities);
+            assignments[node_id] = Some(community);
+        }
+
+        let modularity = self.modularity(&assignments, weight_fn);
+
+        Communities {
+            assignments,
+            num_communities: renumbered.len(),
+            modularity,
+        }
+    }
+}
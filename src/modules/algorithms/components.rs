@@ -0,0 +1,346 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::VecDeque;
+
+/// Union-find over node ids, kept up to date by the graph while component tracking is enabled.
+///
+/// Connections only ever merge components, so `nodes_connection_set` is applied incrementally.
+/// Removing a connection or a node may split a component, which union-find cannot undo, so those
+/// only mark the tracker for a rebuild on the next query.
+#[derive(Clone, Debug)]
+pub(crate) struct ComponentTracker {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    num_components: usize,
+    needs_rebuild: bool,
+}
+
+impl ComponentTracker {
+    pub(crate) fn new() -> Self {
+        Self {
+            parents: Vec::new(),
+            ranks: Vec::new(),
+            num_components: 0,
+            needs_rebuild: true,
+        }
+    }
+
+    pub(crate) fn node_created(&mut self, node_id: usize) {
+        if self.needs_rebuild {
+            return;
+        }
+
+        self.make_set(node_id);
+    }
+
+    pub(crate) fn nodes_connected(&mut self, first_node_id: usize, second_node_id: usize) {
+        if self.needs_rebuild {
+            return;
+        }
+
+        self.union(first_node_id, second_node_id);
+    }
+
+    pub(crate) fn invalidate(&mut self) {
+        self.needs_rebuild = true;
+    }
+
+    fn make_set(&mut self, node_id: usize) {
+        if node_id >= self.parents.len() {
+            let old_len = self.parents.len();
+            self.parents.extend(old_len..=node_id);
+            self.ranks.resize(node_id + 1, 0);
+        }
+
+        self.parents[node_id] = node_id;
+        self.ranks[node_id] = 0;
+        self.num_components += 1;
+    }
+
+    fn find(&mut self, node_id: usize) -> usize {
+        let mut root = node_id;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // INFO: path compression.
+        let mut current = node_id;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    fn union(&mut self, first_node_id: usize, second_node_id: usize) {
+        let first_root = self.find(first_node_id);
+        let second_root = self.find(second_node_id);
+        if first_root == second_root {
+            return;
+        }
+
+        // INFO: union by rank.
+        match self.ranks[first_root].cmp(&self.ranks[second_root]) {
+            std::cmp::Ordering::Less => self.parents[first_root] = second_root,
+            std::cmp::Ordering::Greater => self.parents[second_root] = first_root,
+            std::cmp::Ordering::Equal => {
+                self.parents[second_root] = first_root;
+                self.ranks[first_root] += 1;
+            }
+        }
+
+        self.num_components -= 1;
+    }
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Find the weakly connected components, ignoring connection direction.
+    ///
+    /// Each component is a list of node ids in ascending order, and the components are ordered by
+    /// their smallest node id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Create two islands: 0 -> 1 <- 2, and 3.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// let node_id3 = graph.node_create(3);
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id2, node_id1, 1.0).unwrap();
+    ///
+    /// let components = graph.weakly_connected_components();
+    ///
+    /// assert_eq!(components, vec![vec![node_id0, node_id1, node_id2], vec![node_id3]]);
+    /// ```
+    pub fn weakly_connected_components(&self) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.nodes_vector.len()];
+        let mut components = Vec::new();
+        let mut queue = VecDeque::new();
+
+        for start_node_id in self.node_ids() {
+            if visited[start_node_id] {
+                continue;
+            }
+
+            visited[start_node_id] = true;
+            queue.push_back(start_node_id);
+            let mut component = Vec::new();
+
+            while let Some(node_id) = queue.pop_front() {
+                component.push(node_id);
+
+                for neighbor_id in self.forward_ids(node_id).chain(self.backward_ids(node_id)) {
+                    if !visited[neighbor_id] {
+                        visited[neighbor_id] = true;
+                        queue.push_back(neighbor_id);
+                    }
+                }
+            }
+
+            component.sort_unstable();
+            components.push(component);
+        }
+
+        components
+    }
+
+    /// Strongly connected components of the subgraph of nodes with `allowed[node_id]` set.
+    ///
+    /// Iterative Tarjan, so deep graphs do not overflow the stack. Components come out in reverse
+    /// topological order of the condensation (sinks first).
+    pub(crate) fn strongly_connected_components_within(&self, allowed: &[bool]) -> Vec<Vec<usize>> {
+        let num_slots = self.nodes_vector.len();
+        let mut indices = vec![usize::MAX; num_slots];
+        let mut low_links = vec![0; num_slots];
+        let mut on_stack = vec![false; num_slots];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for start_node_id in self.node_ids() {
+            if !allowed[start_node_id] || indices[start_node_id] != usize::MAX {
+                continue;
+            }
+
+            // INFO: call stack of (node id, position in its forward connections).
+            let mut call_stack = vec![(start_node_id, 0)];
+            indices[start_node_id] = next_index;
+            low_links[start_node_id] = next_index;
+            next_index += 1;
+            stack.push(start_node_id);
+            on_stack[start_node_id] = true;
+
+            while let Some(&mut (node_id, ref mut position)) = call_stack.last_mut() {
+                let connections = self.nodes_vector[node_id]
+                    .as_ref()
+                    .map(|node| node.connections_forward.data().as_slice())
+                    .unwrap_or(&[]);
+
+                if let Some(connection) = connections.get(*position) {
+                    *position += 1;
+                    let connection_node_id = connection.node_id();
+                    if !allowed[connection_node_id] {
+                        continue;
+                    }
+
+                    if indices[connection_node_id] == usize::MAX {
+                        indices[connection_node_id] = next_index;
+                        low_links[connection_node_id] = next_index;
+                        next_index += 1;
+                        stack.push(connection_node_id);
+                        on_stack[connection_node_id] = true;
+                        call_stack.push((connection_node_id, 0));
+                    } else if on_stack[connection_node_id] {
+                        low_links[node_id] = low_links[node_id].min(indices[connection_node_id]);
+                    }
+                    continue;
+                }
+
+                call_stack.pop();
+                if let Some(&(parent_node_id, _)) = call_stack.last() {
+                    low_links[parent_node_id] = low_links[parent_node_id].min(low_links[node_id]);
+                }
+
+                if low_links[node_id] == indices[node_id] {
+                    let mut component = Vec::new();
+                    while let Some(member_node_id) = stack.pop() {
+                        on_stack[member_node_id] = false;
+                        component.push(member_node_id);
+                        if member_node_id == node_id {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    /// Start maintaining weakly connected component membership incrementally.
+    ///
+    /// While enabled, `node_create` and `nodes_connection_set` update the components directly.
+    /// `nodes_connection_remove` and `node_delete` can split a component, so they mark the
+    /// components stale and the next query recomputes them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph and enable tracking.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    /// graph.component_tracking_enable();
+    ///
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// assert_eq!(graph.component_count().unwrap(), 2);
+    ///
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    /// assert!(graph.nodes_same_component(node_id0, node_id1).unwrap());
+    ///
+    /// // INFO: Pruning the only connection splits the component again.
+    /// graph.nodes_connection_remove(node_id0, node_id1).unwrap();
+    /// assert_eq!(graph.component_count().unwrap(), 2);
+    /// ```
+    pub fn component_tracking_enable(&mut self) {
+        if self.component_tracker.is_none() {
+            self.component_tracker = Some(ComponentTracker::new());
+        }
+    }
+
+    /// Stop maintaining component membership and free the tracker.
+    pub fn component_tracking_disable(&mut self) {
+        self.component_tracker = None;
+    }
+
+    /// Check if component tracking is enabled.
+    pub fn component_tracking_enabled(&self) -> bool {
+        self.component_tracker.is_some()
+    }
+
+    /// Get the representative node id of the component this node belongs to.
+    ///
+    /// Two nodes are in the same component exactly when their representatives are equal.
+    /// Representatives are only stable until the graph is next modified.
+    pub fn component_id(&mut self, node_id: usize) -> Result<usize, VeloxGraphError> {
+        self.node_ref(node_id)?;
+        let tracker = self.component_tracker_updated()?;
+
+        Ok(tracker.find(node_id))
+    }
+
+    /// Check if two nodes are in the same weakly connected component.
+    pub fn nodes_same_component(
+        &mut self,
+        first_node_id: usize,
+        second_node_id: usize,
+    ) -> Result<bool, VeloxGraphError> {
+        let first_component_id = self.component_id(first_node_id)?;
+        let second_component_id = self.component_id(second_node_id)?;
+
+        Ok(first_component_id == second_component_id)
+    }
+
+    /// Get the number of weakly connected components.
+    pub fn component_count(&mut self) -> Result<usize, VeloxGraphError> {
+        let tracker = self.component_tracker_updated()?;
+
+        Ok(tracker.num_components)
+    }
+
+    fn component_tracker_updated(&mut self) -> Result<&mut ComponentTracker, VeloxGraphError> {
+        let needs_rebuild = match &self.component_tracker {
+            Some(tracker) => tracker.needs_rebuild,
+            None => return Err(VeloxGraphError::ComponentTrackingDisabled),
+        };
+
+        if needs_rebuild {
+            let mut tracker = ComponentTracker::new();
+            tracker.needs_rebuild = false;
+
+            for node_id in self.node_ids() {
+                tracker.make_set(node_id);
+            }
+
+            for node_id in self.node_ids() {
+                for connection_node_id in self.forward_ids(node_id) {
+                    tracker.union(node_id, connection_node_id);
+                }
+            }
+
+            self.component_tracker = Some(tracker);
+        }
+
+        self.component_tracker
+            .as_mut()
+            .ok_or(VeloxGraphError::ComponentTrackingDisabled)
+    }
+}
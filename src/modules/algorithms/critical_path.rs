@@ -0,0 +1,154 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Result of a critical path run. Per node vectors are indexed by node id and are 0 for empty
+/// slots.
+#[derive(Clone, Debug)]
+pub struct CriticalPath {
+    /// Total time from the first start to the last finish. The cost of the longest path.
+    pub length: f64,
+    /// Node ids along the longest path, from a source node to a sink node.
+    pub node_ids: Vec<usize>,
+    /// Earliest time each node can start, once every predecessor has finished.
+    pub earliest_start: Vec<f64>,
+    /// Latest time each node can start without delaying the end of the critical path.
+    pub latest_start: Vec<f64>,
+}
+
+impl CriticalPath {
+    /// How long a node can be delayed without delaying the end. 0 for nodes on a critical path.
+    pub fn slack(&self, node_id: usize) -> f64 {
+        self.latest_start[node_id] - self.earliest_start[node_id]
+    }
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Find the longest path through an acyclic graph, along with the earliest and latest start
+    /// time of every node.
+    ///
+    /// Each node takes `node_cost_fn` to finish, and each forward connection adds
+    /// `connection_weight_fn` before the next node can start. Use `|_| 0.0` for either to ignore
+    /// it. Returns `CycleDetected` if the graph has a cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph. Node data is the processing time of each neuron.
+    /// let mut graph: VeloxGraphVec<usize, f64, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Two chains from node0 to node3, the one through node1 is slower.
+    /// for cost in [1.0, 4.0, 1.0, 1.0] {
+    ///     graph.node_create(cost);
+    /// }
+    /// for (first, second) in [(0, 1), (1, 3), (0, 2), (2, 3)] {
+    ///     graph.nodes_connection_set(first, second, 0.5).unwrap();
+    /// }
+    ///
+    /// let critical_path = graph
+    ///     .critical_path(|cost| *cost, |delay| *delay)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(critical_path.length, 7.0);
+    /// assert_eq!(critical_path.node_ids, vec![0, 1, 3]);
+    /// assert_eq!(critical_path.earliest_start[2], 1.5);
+    /// assert_eq!(critical_path.slack(2), 3.0);
+    /// ```
+    pub fn critical_path<NodeCostFn, ConnectionWeightFn>(
+        &self,
+        node_cost_fn: NodeCostFn,
+        connection_weight_fn: ConnectionWeightFn,
+    ) -> Result<CriticalPath, VeloxGraphError>
+    where
+        NodeCostFn: Fn(&NodeDataT) -> f64,
+        ConnectionWeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        let order = self.topological_order()?;
+        let num_slots = self.nodes_vector.len();
+
+        let mut costs = vec![0.0; num_slots];
+        for (node_id, node) in self.nodes() {
+            costs[node_id] = node_cost_fn(&node.data);
+        }
+
+        // INFO: forward pass. Remember which predecessor set each earliest start.
+        let mut earliest_start = vec![0.0; num_slots];
+        let mut critical_predecessors = vec![usize::MAX; num_slots];
+        for &node_id in &order {
+            let node = self.node_ref(node_id)?;
+            let earliest_finish = earliest_start[node_id] + costs[node_id];
+
+            for connection in node.connections_forward.data() {
+                let connection_node_id = connection.node_id();
+                let arrival = earliest_finish + connection_weight_fn(&connection.data);
+                if critical_predecessors[connection_node_id] == usize::MAX
+                    || arrival > earliest_start[connection_node_id]
+                {
+                    earliest_start[connection_node_id] = arrival;
+                    critical_predecessors[connection_node_id] = node_id;
+                }
+            }
+        }
+
+        let Some(last_node_id) = order.iter().copied().max_by(|&first, &second| {
+            (earliest_start[first] + costs[first])
+                .total_cmp(&(earliest_start[second] + costs[second]))
+                .then_with(|| second.cmp(&first))
+        }) else {
+            return Ok(CriticalPath {
+                length: 0.0,
+                node_ids: Vec::new(),
+                earliest_start,
+                latest_start: vec![0.0; num_slots],
+            });
+        };
+        let length = earliest_start[last_node_id] + costs[last_node_id];
+
+        // INFO: backward pass. Sinks may finish as late as the end of the critical path.
+        let mut latest_start = vec![0.0; num_slots];
+        for &node_id in order.iter().rev() {
+            let node = self.node_ref(node_id)?;
+            let latest_finish = node
+                .connections_forward
+                .data()
+                .iter()
+                .map(|connection| {
+                    latest_start[connection.node_id()] - connection_weight_fn(&connection.data)
+                })
+                .fold(length, f64::min);
+            latest_start[node_id] = latest_finish - costs[node_id];
+        }
+
+        let mut node_ids = vec![last_node_id];
+        while let Some(&node_id) = node_ids.last() {
+            match critical_predecessors[node_id] {
+                usize::MAX => break,
+                predecessor_id => node_ids.push(predecessor_id),
+            }
+        }
+        node_ids.reverse();
+
+        Ok(CriticalPath {
+            length,
+            node_ids,
+            earliest_start,
+            latest_start,
+        })
+    }
+}
@@ -0,0 +1,273 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::HashSet;
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Collect every simple directed cycle with at most `max_length` nodes (`None` for no limit).
+    ///
+    /// Each cycle is a list of node ids that starts with its smallest node id and follows the
+    /// forward connections. A self connection is a cycle of length 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: A loop 0 -> 1 -> 2 -> 0 with a shortcut 1 -> 0.
+    /// for index in 0..3 {
+    ///     graph.node_create(index);
+    /// }
+    /// for (first, second) in [(0, 1), (1, 2), (2, 0), (1, 0)] {
+    ///     graph.nodes_connection_set(first, second, 1.0).unwrap();
+    /// }
+    ///
+    /// assert_eq!(graph.simple_cycles(None), vec![vec![0, 1], vec![0, 1, 2]]);
+    /// assert_eq!(graph.simple_cycles(Some(2)), vec![vec![0, 1]]);
+    /// ```
+    pub fn simple_cycles(&self, max_length: Option<usize>) -> Vec<Vec<usize>> {
+        let mut cycles = Vec::new();
+        self.simple_cycles_for_each(max_length, |cycle| cycles.push(cycle.to_vec()));
+
+        cycles.sort_unstable();
+        cycles
+    }
+
+    /// Call `cycle_fn` with every simple directed cycle with at most `max_length` nodes (`None`
+    /// for no limit).
+    ///
+    /// Uses Johnson's algorithm, or the length bounded variant of Gupta and Suzumura when
+    /// `max_length` is set. Cycles are streamed as they are found, so they are never all held in
+    /// memory at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Two recurrent loops through node0.
+    /// for index in 0..3 {
+    ///     graph.node_create(index);
+    /// }
+    /// for (first, second) in [(0, 1), (1, 0), (0, 2), (2, 0)] {
+    ///     graph.nodes_connection_set(first, second, 1.0).unwrap();
+    /// }
+    ///
+    /// let mut num_cycles = 0;
+    /// graph.simple_cycles_for_each(None, |cycle| {
+    ///     assert_eq!(cycle[0], 0);
+    ///     num_cycles += 1;
+    /// });
+    ///
+    /// assert_eq!(num_cycles, 2);
+    /// ```
+    pub fn simple_cycles_for_each<CycleFn>(&self, max_length: Option<usize>, mut cycle_fn: CycleFn)
+    where
+        CycleFn: FnMut(&[usize]),
+    {
+        if max_length == Some(0) {
+            return;
+        }
+
+        for node_id in self.node_ids() {
+            if self.has_connection(node_id, node_id) {
+                cycle_fn(&[node_id]);
+            }
+        }
+        if max_length == Some(1) {
+            return;
+        }
+
+        // INFO: search each strongly connected component from its smallest node, then drop that
+        // node and split what is left into components again.
+        let mut allowed = vec![false; self.nodes_vector.len()];
+        let mut components: Vec<Vec<usize>> = {
+            self.node_ids().for_each(|node_id| allowed[node_id] = true);
+            self.strongly_connected_components_within(&allowed)
+        };
+        allowed
+            .iter_mut()
+            .for_each(|is_allowed| *is_allowed = false);
+
+        while let Some(component) = components.pop() {
+            if component.len() < 2 {
+                continue;
+            }
+
+            component
+                .iter()
+                .for_each(|&node_id| allowed[node_id] = true);
+            let start_node_id = component[0];
+
+            match max_length {
+                Some(max_length) => {
+                    self.bounded_cycle_search(start_node_id, &allowed, max_length, &mut cycle_fn)
+                }
+                None => self.johnson_cycle_search(start_node_id, &allowed, &mut cycle_fn),
+            }
+
+            allowed[start_node_id] = false;
+            components.extend(self.strongly_connected_components_within(&allowed));
+            component
+                .iter()
+                .for_each(|&node_id| allowed[node_id] = false);
+        }
+    }
+
+    /// Forward connections of `node_id` that stay inside `allowed`, excluding self connections.
+    fn cycle_successors(&self, node_id: usize, allowed: &[bool]) -> Vec<usize> {
+        self.forward_ids(node_id)
+            .filter(|&connection_node_id| {
+                connection_node_id != node_id && allowed[connection_node_id]
+            })
+            .collect()
+    }
+
+    fn johnson_cycle_search<CycleFn>(
+        &self,
+        start_node_id: usize,
+        allowed: &[bool],
+        cycle_fn: &mut CycleFn,
+    ) where
+        CycleFn: FnMut(&[usize]),
+    {
+        let num_slots = self.nodes_vector.len();
+        let mut blocked = vec![false; num_slots];
+        let mut blocked_by: Vec<HashSet<usize>> = vec![HashSet::new(); num_slots];
+
+        let mut path = vec![start_node_id];
+        blocked[start_node_id] = true;
+        let mut stack = vec![(self.cycle_successors(start_node_id, allowed), 0)];
+        let mut closed = vec![false];
+
+        while let Some((successors, position)) = stack.last_mut() {
+            if let Some(&successor_id) = successors.get(*position) {
+                *position += 1;
+
+                if successor_id == start_node_id {
+                    cycle_fn(&path);
+                    *closed.last_mut().unwrap() = true;
+                } else if !blocked[successor_id] {
+                    path.push(successor_id);
+                    closed.push(false);
+                    blocked[successor_id] = true;
+                    stack.push((self.cycle_successors(successor_id, allowed), 0));
+                }
+                continue;
+            }
+
+            let (successors, _) = stack.pop().unwrap();
+            let node_id = path.pop().unwrap();
+
+            if closed.pop().unwrap() {
+                if let Some(parent_closed) = closed.last_mut() {
+                    *parent_closed = true;
+                }
+
+                let mut unblock_stack = vec![node_id];
+                while let Some(unblock_node_id) = unblock_stack.pop() {
+                    if blocked[unblock_node_id] {
+                        blocked[unblock_node_id] = false;
+                        unblock_stack.extend(blocked_by[unblock_node_id].drain());
+                    }
+                }
+            } else {
+                for successor_id in successors {
+                    blocked_by[successor_id].insert(node_id);
+                }
+            }
+        }
+    }
+
+    fn bounded_cycle_search<CycleFn>(
+        &self,
+        start_node_id: usize,
+        allowed: &[bool],
+        max_length: usize,
+        cycle_fn: &mut CycleFn,
+    ) where
+        CycleFn: FnMut(&[usize]),
+    {
+        let num_slots = self.nodes_vector.len();
+        // INFO: a node may only be entered again at a depth below its lock.
+        let mut locks = vec![max_length; num_slots];
+        let mut blocked_by: Vec<HashSet<usize>> = vec![HashSet::new(); num_slots];
+        let mut on_path = vec![false; num_slots];
+
+        let mut path = vec![start_node_id];
+        locks[start_node_id] = 0;
+        on_path[start_node_id] = true;
+        let mut stack = vec![(self.cycle_successors(start_node_id, allowed), 0)];
+        // INFO: shortest distance back to the start found below each path entry.
+        let mut back_lengths = vec![max_length];
+
+        while let Some((successors, position)) = stack.last_mut() {
+            if let Some(&successor_id) = successors.get(*position) {
+                *position += 1;
+
+                if successor_id == start_node_id {
+                    cycle_fn(&path);
+                    *back_lengths.last_mut().unwrap() = 1;
+                } else if path.len() < locks[successor_id] {
+                    locks[successor_id] = path.len();
+                    path.push(successor_id);
+                    on_path[successor_id] = true;
+                    back_lengths.push(max_length);
+                    stack.push((self.cycle_successors(successor_id, allowed), 0));
+                }
+                continue;
+            }
+
+            let (successors, _) = stack.pop().unwrap();
+            let node_id = path.pop().unwrap();
+            on_path[node_id] = false;
+            let back_length = back_lengths.pop().unwrap();
+            if let Some(parent_back_length) = back_lengths.last_mut() {
+                // INFO: the parent is one connection further from the start than this node.
+                *parent_back_length = (*parent_back_length).min(back_length + 1);
+            }
+
+            if back_length < max_length {
+                // INFO: a cycle was found below this node, so relax the locks that can now lead to
+                // a short enough cycle.
+                let mut relax_stack = vec![(back_length, node_id)];
+                while let Some((relax_length, relax_node_id)) = relax_stack.pop() {
+                    let relaxed_lock = max_length + 1 - relax_length;
+                    if locks[relax_node_id] < relaxed_lock {
+                        locks[relax_node_id] = relaxed_lock;
+                        relax_stack.extend(
+                            blocked_by[relax_node_id]
+                                .iter()
+                                .filter(|&&blocker_id| !on_path[blocker_id])
+                                .map(|&blocker_id| (relax_length + 1, blocker_id)),
+                        );
+                    }
+                }
+            } else {
+                for successor_id in successors {
+                    blocked_by[successor_id].insert(node_id);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::VecDeque;
+
+/// Residual capacity below this is treated as saturated.
+const FLOW_EPSILON: f64 = 1e-12;
+
+/// Result of a maximum flow run.
+#[derive(Clone, Debug)]
+pub struct MaxFlow {
+    /// Total flow from the source to the sink. Equal to the capacity of the minimum cut.
+    pub value: f64,
+    /// Flow on every connection that carries any, as `(from_node_id, to_node_id, flow)`.
+    pub flows: Vec<(usize, usize, f64)>,
+    /// Nodes on the source side of the minimum cut, in ascending order.
+    pub source_side: Vec<usize>,
+    /// Nodes on the sink side of the minimum cut, in ascending order.
+    pub sink_side: Vec<usize>,
+    /// Connections from the source side to the sink side, as `(from_node_id, to_node_id)`.
+    /// Removing them disconnects the sink from the source.
+    pub cut_connections: Vec<(usize, usize)>,
+}
+
+struct ResidualEdge {
+    from: usize,
+    to: usize,
+    capacity: f64,
+    reverse: usize,
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Compute the maximum flow from `source_node_id` to `sink_node_id` with Dinic's algorithm.
+    ///
+    /// `capacity_fn` gives the capacity of each forward connection. Negative capacities are
+    /// treated as 0 and self connections are ignored. Use `|_| 1.0` to find the fewest
+    /// connections that separate the two nodes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Two routes from node0 to node3, each with a narrow connection.
+    /// for index in 0..4 {
+    ///     graph.node_create(index);
+    /// }
+    /// graph.nodes_connection_set(0, 1, 5.0).unwrap();
+    /// graph.nodes_connection_set(1, 3, 2.0).unwrap();
+    /// graph.nodes_connection_set(0, 2, 1.0).unwrap();
+    /// graph.nodes_connection_set(2, 3, 5.0).unwrap();
+    ///
+    /// let max_flow = graph.max_flow(0, 3, |capacity| *capacity).unwrap();
+    ///
+    /// assert_eq!(max_flow.value, 3.0);
+    /// assert_eq!(max_flow.source_side, vec![0, 1]);
+    /// assert_eq!(max_flow.cut_connections, vec![(0, 2), (1, 3)]);
+    /// ```
+    pub fn max_flow<CapacityFn>(
+        &self,
+        source_node_id: usize,
+        sink_node_id: usize,
+        capacity_fn: CapacityFn,
+    ) -> Result<MaxFlow, VeloxGraphError>
+    where
+        CapacityFn: Fn(&ConnectionDataT) -> f64,
+    {
+        self.node_ref(source_node_id)?;
+        self.node_ref(sink_node_id)?;
+        if source_node_id == sink_node_id {
+            return Err(VeloxGraphError::InvalidArgument(
+                "max flow source and sink must be different nodes".to_string(),
+            ));
+        }
+
+        // INFO: residual graph. Every connection gets a paired reverse edge right after it.
+        let num_slots = self.nodes_vector.len();
+        let mut edges: Vec<ResidualEdge> = Vec::new();
+        let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); num_slots];
+        let mut original_capacities = Vec::new();
+
+        for (node_id, node) in self.nodes() {
+            for connection in node.connections_forward.data() {
+                let connection_node_id = connection.node_id();
+                if connection_node_id == node_id {
+                    continue;
+                }
+
+                let capacity = capacity_fn(&connection.data).max(0.0);
+                let edge_index = edges.len();
+                edges.push(ResidualEdge {
+                    from: node_id,
+                    to: connection_node_id,
+                    capacity,
+                    reverse: edge_index + 1,
+                });
+                edges.push(ResidualEdge {
+                    from: connection_node_id,
+                    to: node_id,
+                    capacity: 0.0,
+                    reverse: edge_index,
+                });
+                adjacency[node_id].push(edge_index);
+                adjacency[connection_node_id].push(edge_index + 1);
+                original_capacities.push(capacity);
+            }
+        }
+
+        let mut value = 0.0;
+        let mut levels = vec![usize::MAX; num_slots];
+        let mut next_edges = vec![0; num_slots];
+
+        while residual_levels(&edges, &adjacency, source_node_id, &mut levels)[sink_node_id]
+            != usize::MAX
+        {
+            next_edges.iter_mut().for_each(|next_edge| *next_edge = 0);
+
+            // INFO: blocking flow, walking the level graph with an explicit stack.
+            let mut path: Vec<usize> = Vec::new();
+            let mut node_id = source_node_id;
+            loop {
+                if node_id == sink_node_id {
+                    let bottleneck = path
+                        .iter()
+                        .map(|&edge_index| edges[edge_index].capacity)
+                        .fold(f64::INFINITY, f64::min);
+                    for &edge_index in &path {
+                        edges[edge_index].capacity -= bottleneck;
+                        let reverse = edges[edge_index].reverse;
+                        edges[reverse].capacity += bottleneck;
+                    }
+                    value += bottleneck;
+
+                    path.clear();
+                    node_id = source_node_id;
+                    continue;
+                }
+
+                let mut advanced = false;
+                while next_edges[node_id] < adjacency[node_id].len() {
+                    let edge = &edges[adjacency[node_id][next_edges[node_id]]];
+                    if edge.capacity > FLOW_EPSILON && levels[edge.to] == levels[node_id] + 1 {
+                        path.push(adjacency[node_id][next_edges[node_id]]);
+                        node_id = edge.to;
+                        advanced = true;
+                        break;
+                    }
+                    next_edges[node_id] += 1;
+                }
+
+                if !advanced {
+                    // INFO: dead end. Retreat and skip the edge that led here.
+                    levels[node_id] = usize::MAX;
+                    match path.pop() {
+                        Some(edge_index) => {
+                            node_id = edges[edge_index].from;
+                            next_edges[node_id] += 1;
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        // INFO: the source side of the minimum cut is everything still reachable in the residual graph.
+        residual_levels(&edges, &adjacency, source_node_id, &mut levels);
+        let (source_side, sink_side): (Vec<usize>, Vec<usize>) = self
+            .node_ids()
+            .partition(|&node_id| levels[node_id] != usize::MAX);
+
+        let mut flows = Vec::new();
+        let mut cut_connections = Vec::new();
+        for (connection_index, original_capacity) in original_capacities.into_iter().enumerate() {
+            let edge = &edges[connection_index * 2];
+            let flow = original_capacity - edge.capacity;
+            if flow > FLOW_EPSILON {
+                flows.push((edge.from, edge.to, flow));
+            }
+            if levels[edge.from] != usize::MAX && levels[edge.to] == usize::MAX {
+                cut_connections.push((edge.from, edge.to));
+            }
+        }
+        cut_connections.sort_unstable();
+
+        Ok(MaxFlow {
+            value,
+            flows,
+            source_side,
+            sink_side,
+            cut_connections,
+        })
+    }
+}
+
+/// Breadth first levels over edges with residual capacity. `usize::MAX` if unreachable.
+fn residual_levels<'a>(
+    edges: &[ResidualEdge],
+    adjacency: &[Vec<usize>],
+    source_node_id: usize,
+    levels: &'a mut [usize],
+) -> &'a [usize] {
+    levels.iter_mut().for_each(|level| *level = usize::MAX);
+    levels[source_node_id] = 0;
+    let mut queue = VecDeque::from([source_node_id]);
+
+    while let Some(node_id) = queue.pop_front() {
+        for &edge_index in &adjacency[node_id] {
+            let edge = &edges[edge_index];
+            if edge.capacity > FLOW_EPSILON && levels[edge.to] == usize::MAX {
+                levels[edge.to] = levels[node_id] + 1;
+                queue.push_back(edge.to);
+            }
+        }
+    }
+
+    levels
+}
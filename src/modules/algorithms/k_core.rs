@@ -0,0 +1,165 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Which degree the k-core decomposition peels nodes by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoreDegree {
+    /// Forward plus backward connections.
+    Total,
+    /// Backward connections only.
+    In,
+    /// Forward connections only.
+    Out,
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Compute the core number of every node.
+    ///
+    /// A node's core number is the largest k such that it belongs to a k-core: a subgraph where
+    /// every node has at least k connections (of the chosen `degree`) inside the subgraph. The
+    /// result is indexed by node id, empty slots are 0, and self connections are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::algorithms::k_core::CoreDegree;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: A triangle (0, 1, 2) with a tail node3 hanging off node2.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// let node_id3 = graph.node_create(3);
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id1, node_id2, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id2, node_id0, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id2, node_id3, 1.0).unwrap();
+    ///
+    /// let core_numbers = graph.core_numbers(CoreDegree::Total);
+    ///
+    /// assert_eq!(core_numbers, vec![2, 2, 2, 1]);
+    /// ```
+    pub fn core_numbers(&self, degree: CoreDegree) -> Vec<usize> {
+        let num_slots = self.nodes_vector.len();
+
+        // INFO: nodes whose degree drops when this node is peeled.
+        let dependents = |node_id: usize| -> Vec<usize> {
+            let dependents: Vec<usize> = match degree {
+                CoreDegree::Total => self
+                    .forward_ids(node_id)
+                    .chain(self.backward_ids(node_id))
+                    .collect(),
+                CoreDegree::In => self.forward_ids(node_id).collect(),
+                CoreDegree::Out => self.backward_ids(node_id).collect(),
+            };
+            dependents
+                .into_iter()
+                .filter(|&dependent_id| dependent_id != node_id)
+                .collect()
+        };
+
+        let mut degrees = vec![0; num_slots];
+        for node_id in self.node_ids() {
+            for dependent_id in dependents(node_id) {
+                degrees[dependent_id] += 1;
+            }
+        }
+
+        // INFO: Batagelj and Zaversnik: bucket sort the nodes by degree, then peel in order while
+        // keeping the buckets sorted.
+        let max_degree = degrees.iter().copied().max().unwrap_or(0);
+        let mut bin_starts = vec![0; max_degree + 2];
+        for node_id in self.node_ids() {
+            bin_starts[degrees[node_id] + 1] += 1;
+        }
+        for bin_index in 1..bin_starts.len() {
+            bin_starts[bin_index] += bin_starts[bin_index - 1];
+        }
+
+        let mut sorted_node_ids = vec![0; self.node_ids().count()];
+        let mut positions = vec![0; num_slots];
+        let mut next_free = bin_starts.clone();
+        for node_id in self.node_ids() {
+            let position = next_free[degrees[node_id]];
+            sorted_node_ids[position] = node_id;
+            positions[node_id] = position;
+            next_free[degrees[node_id]] += 1;
+        }
+
+        let mut peeled = vec![false; num_slots];
+        for index in 0..sorted_node_ids.len() {
+            let node_id = sorted_node_ids[index];
+            peeled[node_id] = true;
+
+            for dependent_id in dependents(node_id) {
+                if peeled[dependent_id] || degrees[dependent_id] <= degrees[node_id] {
+                    continue;
+                }
+
+                // INFO: swap the dependent to the front of its bin, then shrink the bin.
+                let dependent_degree = degrees[dependent_id];
+                let dependent_position = positions[dependent_id];
+                let bin_start = bin_starts[dependent_degree];
+                let first_node_id = sorted_node_ids[bin_start];
+                if first_node_id != dependent_id {
+                    sorted_node_ids.swap(bin_start, dependent_position);
+                    positions[first_node_id] = dependent_position;
+                    positions[dependent_id] = bin_start;
+                }
+                bin_starts[dependent_degree] += 1;
+                degrees[dependent_id] -= 1;
+            }
+        }
+
+        degrees
+    }
+
+    /// Get the ids of the nodes in the k-core, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::algorithms::k_core::CoreDegree;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: A triangle (0, 1, 2) with a tail node3 hanging off node2.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// let node_id3 = graph.node_create(3);
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id1, node_id2, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id2, node_id0, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id2, node_id3, 1.0).unwrap();
+    ///
+    /// assert_eq!(graph.k_core(2, CoreDegree::Total), vec![0, 1, 2]);
+    /// ```
+    pub fn k_core(&self, k: usize, degree: CoreDegree) -> Vec<usize> {
+        let core_numbers = self.core_numbers(degree);
+
+        self.node_ids()
+            .filter(|&node_id| core_numbers[node_id] >= k)
+            .collect()
+    }
+}
@@ -0,0 +1,69 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Group the nodes of a feed forward graph into layers that can be processed in order.
+    ///
+    /// Layer 0 holds the source nodes (no `connections_backward`). Every other node goes in the
+    /// layer after its deepest predecessor, so each node comes after all of its inputs. Node ids
+    /// in each layer are in ascending order. Returns `CycleDetected` if the graph has a cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Inputs 0 and 1, hidden node 2, output 3 with a skip connection from 0.
+    /// for index in 0..4 {
+    ///     graph.node_create(index);
+    /// }
+    /// for (first, second) in [(0, 2), (1, 2), (2, 3), (0, 3)] {
+    ///     graph.nodes_connection_set(first, second, 1.0).unwrap();
+    /// }
+    ///
+    /// let layers = graph.layer_assignment().unwrap();
+    ///
+    /// assert_eq!(layers, vec![vec![0, 1], vec![2], vec![3]]);
+    /// ```
+    pub fn layer_assignment(&self) -> Result<Vec<Vec<usize>>, VeloxGraphError> {
+        let order = self.topological_order()?;
+        let mut depths = vec![0; self.nodes_vector.len()];
+        let mut layers: Vec<Vec<usize>> = Vec::new();
+
+        for node_id in order {
+            let depth = depths[node_id];
+            for connection_node_id in self.forward_ids(node_id) {
+                depths[connection_node_id] = depths[connection_node_id].max(depth + 1);
+            }
+
+            if depth >= layers.len() {
+                layers.resize(depth + 1, Vec::new());
+            }
+            layers[depth].push(node_id);
+        }
+
+        for layer in &mut layers {
+            layer.sort_unstable();
+        }
+
+        Ok(layers)
+    }
+}
@@ -0,0 +1,251 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::graph_trait::Graph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Settings for the PageRank iteration.
+#[derive(Clone, Debug)]
+pub struct PageRankSettings {
+    /// Probability of following a connection instead of teleporting, in [0, 1]. Usually 0.85.
+    pub damping_factor: f64,
+    /// Stop once the L1 change between two iterations drops below this. Must be positive.
+    pub tolerance: f64,
+    /// Give up with `VeloxGraphError::DidNotConverge` after this many iterations.
+    pub max_iterations: usize,
+}
+
+impl Default for PageRankSettings {
+    fn default() -> Self {
+        Self {
+            damping_factor: 0.85,
+            tolerance: 1e-6,
+            max_iterations: 100,
+        }
+    }
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Compute PageRank over forward connections, treating every connection equally.
+    ///
+    /// The result is indexed by node id. Empty slots get a score of 0 and the scores of all used
+    /// nodes sum to 1. Fails with `InvalidArgument` for a damping factor outside [0, 1] or a
+    /// tolerance that is not positive, and with `DidNotConverge` when `max_iterations` runs out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::algorithms::page_rank::PageRankSettings;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Two nodes both point at a third one.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// graph.nodes_connection_set(node_id0, node_id2, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id1, node_id2, 1.0).unwrap();
+    ///
+    /// let ranks = graph.page_rank(&PageRankSettings::default()).unwrap();
+    ///
+    /// assert!(ranks[node_id2] > ranks[node_id0]);
+    /// ```
+    pub fn page_rank(&self, settings: &PageRankSettings) -> Result<Vec<f64>, VeloxGraphError> {
+        self.page_rank_weighted(settings, |_| 1.0)
+    }
+
+    /// Compute PageRank over forward connections, weighting each connection with `weight_fn`.
+    ///
+    /// A node splits its rank between its connections in proportion to their weights. Negative
+    /// weights are treated as 0, and nodes without any positive outgoing weight are dangling: their
+    /// rank is spread over every node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::algorithms::page_rank::PageRankSettings;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: node0 connects strongly to node1 and weakly to node2.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// graph.nodes_connection_set(node_id0, node_id1, 9.0).unwrap();
+    /// graph.nodes_connection_set(node_id0, node_id2, 1.0).unwrap();
+    ///
+    /// let ranks = graph
+    ///     .page_rank_weighted(&PageRankSettings::default(), |weight| *weight)
+    ///     .unwrap();
+    ///
+    /// assert!(ranks[node_id1] > ranks[node_id2]);
+    /// ```
+    pub fn page_rank_weighted<WeightFn>(
+        &self,
+        settings: &PageRankSettings,
+        weight_fn: WeightFn,
+    ) -> Result<Vec<f64>, VeloxGraphError>
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        let num_nodes = self.num_entries();
+        let mut teleport = vec![0.0; self.nodes_vector.len()];
+        for node_id in self.node_ids() {
+            teleport[node_id] = 1.0 / num_nodes as f64;
+        }
+
+        self.page_rank_run(settings, &weight_fn, teleport)
+    }
+
+    /// Compute personalized PageRank, where every teleport jumps back to one of the seed nodes.
+    ///
+    /// Scores measure how important each node is from the point of view of the seed set. Dangling
+    /// rank also returns to the seeds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::algorithms::page_rank::PageRankSettings;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Two separate chains: 0 -> 1 and 2 -> 3.
+    /// let node_id0 = graph.node_create(0);
+    /// let node_id1 = graph.node_create(1);
+    /// let node_id2 = graph.node_create(2);
+    /// let node_id3 = graph.node_create(3);
+    /// graph.nodes_connection_set(node_id0, node_id1, 1.0).unwrap();
+    /// graph.nodes_connection_set(node_id2, node_id3, 1.0).unwrap();
+    ///
+    /// let ranks = graph
+    ///     .page_rank_personalized(&PageRankSettings::default(), &[node_id0], |_| 1.0)
+    ///     .unwrap();
+    ///
+    /// assert!(ranks[node_id1] > 0.0);
+    /// assert_eq!(ranks[node_id3], 0.0);
+    /// ```
+    pub fn page_rank_personalized<WeightFn>(
+        &self,
+        settings: &PageRankSettings,
+        seed_node_ids: &[usize],
+        weight_fn: WeightFn,
+    ) -> Result<Vec<f64>, VeloxGraphError>
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        if seed_node_ids.is_empty() {
+            return Err(VeloxGraphError::InvalidArgument(
+                "personalized PageRank needs at least one seed node".to_string(),
+            ));
+        }
+
+        let mut teleport = vec![0.0; self.nodes_vector.len()];
+        for &seed_node_id in seed_node_ids {
+            self.node_ref(seed_node_id)?;
+            teleport[seed_node_id] += 1.0 / seed_node_ids.len() as f64;
+        }
+
+        self.page_rank_run(settings, &weight_fn, teleport)
+    }
+
+    fn page_rank_run<WeightFn>(
+        &self,
+        settings: &PageRankSettings,
+        weight_fn: &WeightFn,
+        teleport: Vec<f64>,
+    ) -> Result<Vec<f64>, VeloxGraphError>
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        if !(0.0..=1.0).contains(&settings.damping_factor) {
+            return Err(VeloxGraphError::InvalidArgument(format!(
+                "damping factor {} is not between 0 and 1",
+                settings.damping_factor
+            )));
+        }
+        if settings.tolerance.is_nan() || settings.tolerance <= 0.0 {
+            return Err(VeloxGraphError::InvalidArgument(format!(
+                "tolerance {} is not positive",
+                settings.tolerance
+            )));
+        }
+
+        let num_slots = self.nodes_vector.len();
+        let damping_factor = settings.damping_factor;
+
+        // INFO: total outgoing weight per node, so each node can split its rank.
+        let mut weight_totals = vec![0.0; num_slots];
+        for (node_id, node) in self.nodes() {
+            weight_totals[node_id] = node
+                .connections_forward
+                .data()
+                .iter()
+                .map(|connection| weight_fn(&connection.data).max(0.0))
+                .sum();
+        }
+
+        let mut ranks = teleport.clone();
+        let mut next_ranks = vec![0.0; num_slots];
+
+        for _iteration in 0..settings.max_iterations {
+            let dangling_rank: f64 = self
+                .node_ids()
+                .filter(|&node_id| weight_totals[node_id] <= 0.0)
+                .map(|node_id| ranks[node_id])
+                .sum();
+
+            for node_id in self.node_ids() {
+                next_ranks[node_id] =
+                    (1.0 - damping_factor + damping_factor * dangling_rank) * teleport[node_id];
+            }
+
+            for (node_id, node) in self.nodes() {
+                let weight_total = weight_totals[node_id];
+                if weight_total <= 0.0 {
+                    continue;
+                }
+
+                let share = damping_factor * ranks[node_id] / weight_total;
+                for connection in node.connections_forward.data() {
+                    let weight = weight_fn(&connection.data).max(0.0);
+                    next_ranks[connection.node_id()] += share * weight;
+                }
+            }
+
+            let change: f64 = ranks
+                .iter()
+                .zip(next_ranks.iter())
+                .map(|(rank, next_rank)| (rank - next_rank).abs())
+                .sum();
+
+            std::mem::swap(&mut ranks, &mut next_ranks);
+
+            if change < settings.tolerance {
+                return Ok(ranks);
+            }
+        }
+
+        Err(VeloxGraphError::DidNotConverge(settings.max_iterations))
+    }
+}
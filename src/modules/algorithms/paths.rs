@@ -0,0 +1,385 @@
+use crate::modules::algorithms::shortest_paths::MinCostEntry;
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::{BinaryHeap, HashSet};
+
+/// A path through the graph along with its total cost.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedPath {
+    /// Node ids from the source to the target, both included.
+    pub node_ids: Vec<usize>,
+    /// Sum of the connection costs along the path.
+    pub cost: f64,
+}
+
+/// Iterator over the simple paths between two nodes. Created by `VeloxGraph::simple_paths`.
+///
+/// Paths are found depth first, so only the current path is held in memory.
+pub struct SimplePaths<'a, NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    graph: &'a VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>,
+    target_node_id: usize,
+    max_length: usize,
+    path: Vec<usize>,
+    on_path: Vec<bool>,
+    // INFO: one entry per node on the path, holding the successors not yet tried.
+    successors: Vec<std::vec::IntoIter<usize>>,
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT> Iterator
+    for SimplePaths<'_, NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        loop {
+            let successors = self.successors.last_mut()?;
+
+            let Some(next_node_id) = successors.next() else {
+                self.successors.pop();
+                if let Some(node_id) = self.path.pop() {
+                    self.on_path[node_id] = false;
+                }
+                continue;
+            };
+
+            if self.on_path[next_node_id] {
+                continue;
+            }
+
+            if next_node_id == self.target_node_id {
+                let mut path = self.path.clone();
+                path.push(next_node_id);
+                return Some(path);
+            }
+
+            // INFO: only go deeper if the target can still be reached within the length limit.
+            if self.path.len() < self.max_length {
+                self.path.push(next_node_id);
+                self.on_path[next_node_id] = true;
+                self.successors.push(
+                    self.graph
+                        .forward_ids(next_node_id)
+                        .collect::<Vec<_>>()
+                        .into_iter(),
+                );
+            }
+        }
+    }
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Iterate over every simple path from `source_node_id` to `target_node_id` with at most
+    /// `max_length` connections (`None` for no limit).
+    ///
+    /// Each path is a list of node ids following the forward connections, starting with the
+    /// source and ending with the target. No node appears twice in a path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: node0 reaches node3 directly and through node1 -> node2.
+    /// for index in 0..4 {
+    ///     graph.node_create(index);
+    /// }
+    /// for (first, second) in [(0, 1), (1, 2), (2, 3), (0, 3)] {
+    ///     graph.nodes_connection_set(first, second, 1.0).unwrap();
+    /// }
+    ///
+    /// let mut paths: Vec<Vec<usize>> = graph.simple_paths(0, 3, None).unwrap().collect();
+    /// paths.sort();
+    /// assert_eq!(paths, vec![vec![0, 1, 2, 3], vec![0, 3]]);
+    ///
+    /// let short_paths: Vec<Vec<usize>> = graph.simple_paths(0, 3, Some(2)).unwrap().collect();
+    /// assert_eq!(short_paths, vec![vec![0, 3]]);
+    /// ```
+    pub fn simple_paths(
+        &self,
+        source_node_id: usize,
+        target_node_id: usize,
+        max_length: Option<usize>,
+    ) -> Result<
+        SimplePaths<'_, NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>,
+        VeloxGraphError,
+    > {
+        self.node_ref(source_node_id)?;
+        self.node_ref(target_node_id)?;
+        if source_node_id == target_node_id {
+            return Err(VeloxGraphError::InvalidArgument(
+                "path source and target must be different nodes".to_string(),
+            ));
+        }
+
+        let max_length = max_length.unwrap_or(usize::MAX);
+        let mut on_path = vec![false; self.nodes_vector.len()];
+        on_path[source_node_id] = true;
+
+        let successors = if max_length == 0 {
+            Vec::new()
+        } else {
+            vec![self
+                .forward_ids(source_node_id)
+                .collect::<Vec<_>>()
+                .into_iter()]
+        };
+
+        Ok(SimplePaths {
+            graph: self,
+            target_node_id,
+            max_length,
+            path: vec![source_node_id],
+            on_path,
+            successors,
+        })
+    }
+
+    /// Find up to `k` loopless paths from `source_node_id` to `target_node_id` in order of
+    /// increasing cost, using Yen's algorithm.
+    ///
+    /// `cost_fn` gives the cost of each forward connection and must be non-negative. Fails with
+    /// `InvalidArgument` if any cost is negative or NaN. Self connections are ignored. Fewer than
+    /// `k` paths are returned if the graph has no more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Three routes from node0 to node3 with different costs.
+    /// for index in 0..4 {
+    ///     graph.node_create(index);
+    /// }
+    /// graph.nodes_connection_set(0, 1, 1.0).unwrap();
+    /// graph.nodes_connection_set(1, 3, 1.0).unwrap();
+    /// graph.nodes_connection_set(0, 2, 1.0).unwrap();
+    /// graph.nodes_connection_set(2, 3, 2.0).unwrap();
+    /// graph.nodes_connection_set(0, 3, 5.0).unwrap();
+    ///
+    /// let paths = graph.k_shortest_paths(0, 3, 2, |cost| *cost).unwrap();
+    ///
+    /// assert_eq!(paths.len(), 2);
+    /// assert_eq!(paths[0].node_ids, vec![0, 1, 3]);
+    /// assert_eq!(paths[0].cost, 2.0);
+    /// assert_eq!(paths[1].node_ids, vec![0, 2, 3]);
+    /// assert_eq!(paths[1].cost, 3.0);
+    /// ```
+    pub fn k_shortest_paths<CostFn>(
+        &self,
+        source_node_id: usize,
+        target_node_id: usize,
+        k: usize,
+        cost_fn: CostFn,
+    ) -> Result<Vec<WeightedPath>, VeloxGraphError>
+    where
+        CostFn: Fn(&ConnectionDataT) -> f64,
+    {
+        self.node_ref(source_node_id)?;
+        self.node_ref(target_node_id)?;
+        if source_node_id == target_node_id {
+            return Err(VeloxGraphError::InvalidArgument(
+                "path source and target must be different nodes".to_string(),
+            ));
+        }
+        self.costs_check(&cost_fn, true)?;
+
+        let num_slots = self.nodes_vector.len();
+        // INFO: every path is kept with the cost from the source up to each of its nodes.
+        let mut found: Vec<(Vec<usize>, Vec<f64>)> = Vec::new();
+        let mut candidates: Vec<(Vec<usize>, Vec<f64>)> = Vec::new();
+
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+        match self.shortest_path_avoiding(
+            source_node_id,
+            target_node_id,
+            &cost_fn,
+            &vec![false; num_slots],
+            &HashSet::new(),
+        ) {
+            Some(path) => found.push(path),
+            None => return Ok(Vec::new()),
+        }
+
+        while found.len() < k {
+            let (previous_path, previous_costs) = found.last().unwrap().clone();
+
+            for spur_index in 0..previous_path.len() - 1 {
+                let spur_node_id = previous_path[spur_index];
+                let root_path = &previous_path[..=spur_index];
+
+                // INFO: block the next step of every found path that shares this root.
+                let mut blocked_connections = HashSet::new();
+                for (path, _) in &found {
+                    if path.len() > spur_index + 1 && &path[..=spur_index] == root_path {
+                        blocked_connections.insert((path[spur_index], path[spur_index + 1]));
+                    }
+                }
+
+                // INFO: the root nodes before the spur node may not be visited again.
+                let mut blocked_nodes = vec![false; num_slots];
+                for &node_id in &root_path[..spur_index] {
+                    blocked_nodes[node_id] = true;
+                }
+
+                let Some((spur_path, spur_costs)) = self.shortest_path_avoiding(
+                    spur_node_id,
+                    target_node_id,
+                    &cost_fn,
+                    &blocked_nodes,
+                    &blocked_connections,
+                ) else {
+                    continue;
+                };
+
+                let root_cost = previous_costs[spur_index];
+                let mut path = root_path[..spur_index].to_vec();
+                path.extend_from_slice(&spur_path);
+                let mut costs = previous_costs[..spur_index].to_vec();
+                costs.extend(spur_costs.iter().map(|cost| root_cost + cost));
+
+                if !found
+                    .iter()
+                    .chain(candidates.iter())
+                    .any(|(known, _)| *known == path)
+                {
+                    candidates.push((path, costs));
+                }
+            }
+
+            // INFO: the cheapest candidate becomes the next path. Ties go to the fewest nodes.
+            let Some(best_index) = (0..candidates.len()).min_by(|&first, &second| {
+                let (first_path, first_costs) = &candidates[first];
+                let (second_path, second_costs) = &candidates[second];
+                first_costs
+                    .last()
+                    .unwrap()
+                    .total_cmp(second_costs.last().unwrap())
+                    .then_with(|| first_path.len().cmp(&second_path.len()))
+                    .then_with(|| first_path.cmp(second_path))
+            }) else {
+                break;
+            };
+            found.push(candidates.swap_remove(best_index));
+        }
+
+        Ok(found
+            .into_iter()
+            .map(|(node_ids, costs)| WeightedPath {
+                node_ids,
+                cost: *costs.last().unwrap(),
+            })
+            .collect())
+    }
+
+    /// Dijkstra from `source_node_id` to `target_node_id` that skips the blocked nodes and
+    /// connections. Returns the path and the cost up to each of its nodes.
+    fn shortest_path_avoiding<CostFn>(
+        &self,
+        source_node_id: usize,
+        target_node_id: usize,
+        cost_fn: &CostFn,
+        blocked_nodes: &[bool],
+        blocked_connections: &HashSet<(usize, usize)>,
+    ) -> Option<(Vec<usize>, Vec<f64>)>
+    where
+        CostFn: Fn(&ConnectionDataT) -> f64,
+    {
+        let num_slots = self.nodes_vector.len();
+        let mut distances = vec![f64::INFINITY; num_slots];
+        let mut predecessors = vec![usize::MAX; num_slots];
+        let mut finalized = vec![false; num_slots];
+        distances[source_node_id] = 0.0;
+        let mut heap = BinaryHeap::from([MinCostEntry {
+            cost: 0.0,
+            node_id: source_node_id,
+        }]);
+
+        while let Some(MinCostEntry { cost, node_id }) = heap.pop() {
+            if finalized[node_id] || cost > distances[node_id] {
+                continue;
+            }
+            finalized[node_id] = true;
+            if node_id == target_node_id {
+                break;
+            }
+
+            let Ok(node) = self.node_ref(node_id) else {
+                continue;
+            };
+            for connection in node.connections_forward.data() {
+                let connection_node_id = connection.node_id();
+                if connection_node_id == node_id
+                    || finalized[connection_node_id]
+                    || blocked_nodes[connection_node_id]
+                    || blocked_connections.contains(&(node_id, connection_node_id))
+                {
+                    continue;
+                }
+
+                let next_distance = cost + cost_fn(&connection.data);
+                if next_distance < distances[connection_node_id] {
+                    distances[connection_node_id] = next_distance;
+                    predecessors[connection_node_id] = node_id;
+                    heap.push(MinCostEntry {
+                        cost: next_distance,
+                        node_id: connection_node_id,
+                    });
+                }
+            }
+        }
+
+        if !finalized[target_node_id] {
+            return None;
+        }
+
+        let mut path = vec![target_node_id];
+        while let Some(&node_id) = path.last() {
+            if node_id == source_node_id {
+                break;
+            }
+            path.push(predecessors[node_id]);
+        }
+        path.reverse();
+        let costs = path.iter().map(|&node_id| distances[node_id]).collect();
+
+        Some((path, costs))
+    }
+}
@@ -0,0 +1,151 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A vertex centric program run by `VeloxGraph::pregel`.
+pub trait VertexProgram<NodeDataT, ConnectionDataT> {
+    type Message: Clone;
+
+    /// Run for every active node. `messages` holds what arrived since the last superstep, and
+    /// is empty on superstep 0. Return a message to send along every forward connection, or
+    /// `None` to stay quiet.
+    fn compute(
+        &mut self,
+        superstep: usize,
+        node_id: usize,
+        node_data: &mut NodeDataT,
+        messages: &[Self::Message],
+    ) -> Option<Self::Message>;
+
+    /// Change a message as it travels along a connection, e.g. to add the connection weight.
+    /// Sends it unchanged by default.
+    #[allow(unused_variables)]
+    fn message_along(
+        &mut self,
+        message: &Self::Message,
+        connection_data: &ConnectionDataT,
+    ) -> Self::Message {
+        message.clone()
+    }
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Run a vertex program in supersteps until no node is active, and return the number of
+    /// supersteps run.
+    ///
+    /// Every node is active on superstep 0. After that, a node is active when it received
+    /// messages in the previous superstep. Messages are delivered along `connections_forward`
+    /// at the start of the next superstep, in ascending order of the sending node id. Returns
+    /// `DidNotConverge` if nodes are still active after `max_supersteps`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::algorithms::pregel::VertexProgram;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Hop distance from node0. Node data is the distance found so far.
+    /// struct HopDistance;
+    ///
+    /// impl VertexProgram<u32, f64> for HopDistance {
+    ///     type Message = u32;
+    ///
+    ///     fn compute(
+    ///         &mut self,
+    ///         superstep: usize,
+    ///         node_id: usize,
+    ///         distance: &mut u32,
+    ///         messages: &[u32],
+    ///     ) -> Option<u32> {
+    ///         let candidate = match superstep {
+    ///             0 if node_id == 0 => 0,
+    ///             _ => *messages.iter().min()?,
+    ///         };
+    ///         if candidate >= *distance {
+    ///             return None;
+    ///         }
+    ///
+    ///         *distance = candidate;
+    ///         Some(candidate + 1)
+    ///     }
+    /// }
+    ///
+    /// // INFO: Initialize the graph. A chain 0 -> 1 -> 2 with a shortcut 0 -> 2.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    /// for _ in 0..3 {
+    ///     graph.node_create(u32::MAX);
+    /// }
+    /// for (first, second) in [(0, 1), (1, 2), (0, 2)] {
+    ///     graph.nodes_connection_set(first, second, 1.0).unwrap();
+    /// }
+    ///
+    /// let num_supersteps = graph.pregel(&mut HopDistance, 10).unwrap();
+    ///
+    /// assert_eq!(num_supersteps, 3);
+    /// assert_eq!(graph.node_get(2).unwrap().data, 1);
+    /// ```
+    pub fn pregel<ProgramT>(
+        &mut self,
+        program: &mut ProgramT,
+        max_supersteps: usize,
+    ) -> Result<usize, VeloxGraphError>
+    where
+        ProgramT: VertexProgram<NodeDataT, ConnectionDataT>,
+    {
+        let num_slots = self.nodes_vector.len();
+        let mut inboxes: Vec<Vec<ProgramT::Message>> = vec![Vec::new(); num_slots];
+        let mut next_inboxes: Vec<Vec<ProgramT::Message>> = vec![Vec::new(); num_slots];
+        let mut active_node_ids: Vec<usize> = self.node_ids().collect();
+        let mut superstep = 0;
+
+        while !active_node_ids.is_empty() {
+            if superstep == max_supersteps {
+                return Err(VeloxGraphError::DidNotConverge(max_supersteps));
+            }
+
+            for &node_id in &active_node_ids {
+                let Some(Some(node)) = self.nodes_vector.get_mut(node_id) else {
+                    continue;
+                };
+
+                let messages = std::mem::take(&mut inboxes[node_id]);
+                let Some(message) = program.compute(superstep, node_id, &mut node.data, &messages)
+                else {
+                    continue;
+                };
+
+                for connection in node.connections_forward.data() {
+                    let outgoing = program.message_along(&message, &connection.data);
+                    next_inboxes[connection.node_id()].push(outgoing);
+                }
+            }
+
+            std::mem::swap(&mut inboxes, &mut next_inboxes);
+            active_node_ids.clear();
+            active_node_ids.extend(
+                inboxes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, inbox)| !inbox.is_empty())
+                    .map(|(node_id, _)| node_id),
+            );
+            superstep += 1;
+        }
+
+        Ok(superstep)
+    }
+}
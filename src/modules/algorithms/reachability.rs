@@ -0,0 +1,431 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::VecDeque;
+
+/// 2-hop reachability labels over the condensation of a graph snapshot.
+///
+/// Every strongly connected component gets a list of hubs it reaches (`labels_out`) and a list
+/// of hubs that reach it (`labels_in`), built with pruned landmark labelling. A component reaches
+/// another exactly when their lists share a hub, so a query is a merge of two short sorted lists.
+///
+/// New nodes and connections between nodes that already reach each other keep the labels valid.
+/// Any other change marks the index for a rebuild on the next query.
+#[derive(Clone, Debug)]
+pub(crate) struct ReachabilityIndex {
+    component_ids: Vec<usize>,
+    labels_out: Vec<Vec<usize>>,
+    labels_in: Vec<Vec<usize>>,
+    needs_rebuild: bool,
+}
+
+impl ReachabilityIndex {
+    pub(crate) fn new() -> Self {
+        Self {
+            component_ids: Vec::new(),
+            labels_out: Vec::new(),
+            labels_in: Vec::new(),
+            needs_rebuild: true,
+        }
+    }
+
+    pub(crate) fn node_created(&mut self, node_id: usize) {
+        if self.needs_rebuild {
+            return;
+        }
+
+        // INFO: a new node is its own component and hub. Hubs are numbered by rank, so the new
+        // rank is larger than every existing one and the labels stay sorted.
+        let component_id = self.labels_out.len();
+        if node_id >= self.component_ids.len() {
+            self.component_ids.resize(node_id + 1, usize::MAX);
+        }
+        self.component_ids[node_id] = component_id;
+        self.labels_out.push(vec![component_id]);
+        self.labels_in.push(vec![component_id]);
+    }
+
+    pub(crate) fn nodes_connected(&mut self, first_node_id: usize, second_node_id: usize) {
+        if self.needs_rebuild || self.reaches(first_node_id, second_node_id) {
+            return;
+        }
+
+        self.needs_rebuild = true;
+    }
+
+    pub(crate) fn invalidate(&mut self) {
+        self.needs_rebuild = true;
+    }
+
+    fn reaches(&self, first_node_id: usize, second_node_id: usize) -> bool {
+        let first_component_id = self.component_ids[first_node_id];
+        let second_component_id = self.component_ids[second_node_id];
+
+        first_component_id == second_component_id
+            || labels_intersect(
+                &self.labels_out[first_component_id],
+                &self.labels_in[second_component_id],
+            )
+    }
+}
+
+/// Check if two ascending lists share an element.
+fn labels_intersect(first: &[usize], second: &[usize]) -> bool {
+    let (mut first_index, mut second_index) = (0, 0);
+    while first_index < first.len() && second_index < second.len() {
+        match first[first_index].cmp(&second[second_index]) {
+            std::cmp::Ordering::Less => first_index += 1,
+            std::cmp::Ordering::Greater => second_index += 1,
+            std::cmp::Ordering::Equal => return true,
+        }
+    }
+
+    false
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Get every node that can reach `node_id` within `max_depth` connections (`None` for no
+    /// limit), following `connections_backward`.
+    ///
+    /// The result is sorted by node id and never contains `node_id` itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: A chain 0 -> 1 -> 2 -> 3.
+    /// for index in 0..4 {
+    ///     graph.node_create(index);
+    /// }
+    /// for index in 0..3 {
+    ///     graph.nodes_connection_set(index, index + 1, 1.0).unwrap();
+    /// }
+    ///
+    /// assert_eq!(graph.ancestors(3, None).unwrap(), vec![0, 1, 2]);
+    /// assert_eq!(graph.ancestors(3, Some(1)).unwrap(), vec![2]);
+    /// ```
+    pub fn ancestors(
+        &self,
+        node_id: usize,
+        max_depth: Option<usize>,
+    ) -> Result<Vec<usize>, VeloxGraphError> {
+        self.node_ref(node_id)?;
+
+        Ok(self.reachable_within(node_id, max_depth, false))
+    }
+
+    /// Get every node that `node_id` can reach within `max_depth` connections (`None` for no
+    /// limit), following `connections_forward`.
+    ///
+    /// The result is sorted by node id and never contains `node_id` itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: A chain 0 -> 1 -> 2 -> 3.
+    /// for index in 0..4 {
+    ///     graph.node_create(index);
+    /// }
+    /// for index in 0..3 {
+    ///     graph.nodes_connection_set(index, index + 1, 1.0).unwrap();
+    /// }
+    ///
+    /// assert_eq!(graph.descendants(0, None).unwrap(), vec![1, 2, 3]);
+    /// assert_eq!(graph.descendants(0, Some(2)).unwrap(), vec![1, 2]);
+    /// ```
+    pub fn descendants(
+        &self,
+        node_id: usize,
+        max_depth: Option<usize>,
+    ) -> Result<Vec<usize>, VeloxGraphError> {
+        self.node_ref(node_id)?;
+
+        Ok(self.reachable_within(node_id, max_depth, true))
+    }
+
+    /// Check if there is a forward path from `first_node_id` to `second_node_id`.
+    ///
+    /// Every node reaches itself. The search stops as soon as `second_node_id` is found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: A chain 0 -> 1 -> 2.
+    /// for index in 0..3 {
+    ///     graph.node_create(index);
+    /// }
+    /// graph.nodes_connection_set(0, 1, 1.0).unwrap();
+    /// graph.nodes_connection_set(1, 2, 1.0).unwrap();
+    ///
+    /// assert!(graph.is_reachable(0, 2).unwrap());
+    /// assert!(!graph.is_reachable(2, 0).unwrap());
+    /// ```
+    pub fn is_reachable(
+        &self,
+        first_node_id: usize,
+        second_node_id: usize,
+    ) -> Result<bool, VeloxGraphError> {
+        self.node_ref(first_node_id)?;
+        self.node_ref(second_node_id)?;
+        if first_node_id == second_node_id {
+            return Ok(true);
+        }
+
+        let mut visited = vec![false; self.nodes_vector.len()];
+        visited[first_node_id] = true;
+        let mut stack = vec![first_node_id];
+
+        while let Some(node_id) = stack.pop() {
+            for connection_node_id in self.forward_ids(node_id) {
+                if connection_node_id == second_node_id {
+                    return Ok(true);
+                }
+                if !visited[connection_node_id] {
+                    visited[connection_node_id] = true;
+                    stack.push(connection_node_id);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Start keeping a reachability index for fast repeated `is_reachable_indexed` queries.
+    ///
+    /// The index is built on the first query (or by `reachability_index_build`) and then follows
+    /// the graph. Creating nodes, and connecting nodes that already reach each other, keep it
+    /// valid. Other changes mark it for a rebuild on the next query.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph and enable the index.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    /// graph.reachability_index_enable();
+    ///
+    /// // INFO: A chain 0 -> 1 -> 2.
+    /// for index in 0..3 {
+    ///     graph.node_create(index);
+    /// }
+    /// graph.nodes_connection_set(0, 1, 1.0).unwrap();
+    /// graph.nodes_connection_set(1, 2, 1.0).unwrap();
+    ///
+    /// assert!(graph.is_reachable_indexed(0, 2).unwrap());
+    /// assert!(!graph.is_reachable_indexed(2, 0).unwrap());
+    ///
+    /// // INFO: Pruning a connection is picked up by the next query.
+    /// graph.nodes_connection_remove(1, 2).unwrap();
+    /// assert!(!graph.is_reachable_indexed(0, 2).unwrap());
+    /// ```
+    pub fn reachability_index_enable(&mut self) {
+        if self.reachability_index.is_none() {
+            self.reachability_index = Some(ReachabilityIndex::new());
+        }
+    }
+
+    /// Stop maintaining the reachability index and free it.
+    pub fn reachability_index_disable(&mut self) {
+        self.reachability_index = None;
+    }
+
+    /// Check if the reachability index is enabled.
+    pub fn reachability_index_enabled(&self) -> bool {
+        self.reachability_index.is_some()
+    }
+
+    /// Build the reachability index now if it is out of date, instead of on the next query.
+    pub fn reachability_index_build(&mut self) -> Result<(), VeloxGraphError> {
+        self.reachability_index_updated()?;
+
+        Ok(())
+    }
+
+    /// Check if there is a forward path from `first_node_id` to `second_node_id` using the
+    /// reachability index.
+    ///
+    /// Gives the same answer as `is_reachable`. Rebuilds the index first if the graph changed in
+    /// a way the index could not follow.
+    pub fn is_reachable_indexed(
+        &mut self,
+        first_node_id: usize,
+        second_node_id: usize,
+    ) -> Result<bool, VeloxGraphError> {
+        self.node_ref(first_node_id)?;
+        self.node_ref(second_node_id)?;
+        let index = self.reachability_index_updated()?;
+
+        Ok(index.reaches(first_node_id, second_node_id))
+    }
+
+    fn reachability_index_updated(&mut self) -> Result<&ReachabilityIndex, VeloxGraphError> {
+        let needs_rebuild = match &self.reachability_index {
+            Some(index) => index.needs_rebuild,
+            None => return Err(VeloxGraphError::ReachabilityIndexDisabled),
+        };
+
+        if needs_rebuild {
+            self.reachability_index = Some(self.reachability_index_built());
+        }
+
+        self.reachability_index
+            .as_ref()
+            .ok_or(VeloxGraphError::ReachabilityIndexDisabled)
+    }
+
+    /// Pruned landmark labelling over the condensation of the current graph.
+    fn reachability_index_built(&self) -> ReachabilityIndex {
+        let num_slots = self.nodes_vector.len();
+        let components = self.strongly_connected_components_within(&vec![true; num_slots]);
+        let num_components = components.len();
+
+        let mut component_ids = vec![usize::MAX; num_slots];
+        for (component_id, component) in components.iter().enumerate() {
+            for &node_id in component {
+                component_ids[node_id] = component_id;
+            }
+        }
+
+        // INFO: connections between components, without duplicates.
+        let mut successors: Vec<Vec<usize>> = vec![Vec::new(); num_components];
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); num_components];
+        for node_id in self.node_ids() {
+            for connection_node_id in self.forward_ids(node_id) {
+                let from = component_ids[node_id];
+                let to = component_ids[connection_node_id];
+                if from != to {
+                    successors[from].push(to);
+                    predecessors[to].push(from);
+                }
+            }
+        }
+        for neighbors in successors.iter_mut().chain(predecessors.iter_mut()) {
+            neighbors.sort_unstable();
+            neighbors.dedup();
+        }
+
+        // INFO: well connected components first, so they cover most queries and prune early.
+        let mut order: Vec<usize> = (0..num_components).collect();
+        order.sort_by_key(|&component_id| {
+            std::cmp::Reverse(
+                (successors[component_id].len() + 1) * (predecessors[component_id].len() + 1),
+            )
+        });
+
+        let mut index = ReachabilityIndex {
+            component_ids: Vec::new(),
+            labels_out: vec![Vec::new(); num_components],
+            labels_in: vec![Vec::new(); num_components],
+            needs_rebuild: false,
+        };
+        let mut visited_by = vec![usize::MAX; num_components];
+        let mut queue = VecDeque::new();
+
+        for (rank, &hub) in order.iter().enumerate() {
+            // INFO: forward pass adds the hub to the in labels of what it reaches, backward pass
+            // to the out labels of what reaches it. Skip anything already covered by earlier hubs.
+            for forward in [true, false] {
+                let stamp = rank * 2 + forward as usize;
+                visited_by[hub] = stamp;
+                queue.push_back(hub);
+
+                while let Some(component_id) = queue.pop_front() {
+                    let covered = if forward {
+                        labels_intersect(&index.labels_out[hub], &index.labels_in[component_id])
+                    } else {
+                        labels_intersect(&index.labels_out[component_id], &index.labels_in[hub])
+                    };
+                    if covered {
+                        continue;
+                    }
+
+                    let neighbors = if forward {
+                        index.labels_in[component_id].push(rank);
+                        &successors[component_id]
+                    } else {
+                        index.labels_out[component_id].push(rank);
+                        &predecessors[component_id]
+                    };
+                    for &neighbor_id in neighbors {
+                        if visited_by[neighbor_id] != stamp {
+                            visited_by[neighbor_id] = stamp;
+                            queue.push_back(neighbor_id);
+                        }
+                    }
+                }
+            }
+        }
+
+        index.component_ids = component_ids;
+        index
+    }
+
+    /// Breadth first search from `node_id` along forward or backward connections.
+    fn reachable_within(
+        &self,
+        node_id: usize,
+        max_depth: Option<usize>,
+        forward: bool,
+    ) -> Vec<usize> {
+        let max_depth = max_depth.unwrap_or(usize::MAX);
+        let mut depths = vec![usize::MAX; self.nodes_vector.len()];
+        depths[node_id] = 0;
+        let mut queue = VecDeque::from([node_id]);
+        let mut reached = Vec::new();
+
+        while let Some(current_node_id) = queue.pop_front() {
+            let depth = depths[current_node_id];
+            if depth >= max_depth {
+                continue;
+            }
+
+            let neighbor_ids: Vec<usize> = if forward {
+                self.forward_ids(current_node_id).collect()
+            } else {
+                self.backward_ids(current_node_id).collect()
+            };
+            for neighbor_id in neighbor_ids {
+                if depths[neighbor_id] == usize::MAX {
+                    depths[neighbor_id] = depth + 1;
+                    reached.push(neighbor_id);
+                    queue.push_back(neighbor_id);
+                }
+            }
+        }
+
+        reached.sort_unstable();
+        reached
+    }
+}
@@ -0,0 +1,189 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+
+/// Heap entry ordered so that `BinaryHeap` pops the smallest cost first.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct MinCostEntry {
+    pub(crate) cost: f64,
+    pub(crate) node_id: usize,
+}
+
+impl Eq for MinCostEntry {}
+
+impl Ord for MinCostEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.node_id.cmp(&self.node_id))
+    }
+}
+
+impl PartialOrd for MinCostEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Every shortest path out of one source, as needed by Brandes style accumulation.
+pub(crate) struct ShortestPathDag {
+    /// Reached nodes in non-decreasing distance order, starting with the source.
+    pub(crate) order: Vec<usize>,
+    /// For each node, the nodes directly before it on some shortest path.
+    pub(crate) predecessors: Vec<Vec<usize>>,
+    /// For each node, the number of distinct shortest paths from the source.
+    pub(crate) path_counts: Vec<f64>,
+    /// For each node, the distance from the source. `f64::INFINITY` if not reached.
+    pub(crate) distances: Vec<f64>,
+}
+
+impl ShortestPathDag {
+    fn new(num_slots: usize, source_node_id: usize) -> Self {
+        let mut dag = Self {
+            order: Vec::new(),
+            predecessors: vec![Vec::new(); num_slots],
+            path_counts: vec![0.0; num_slots],
+            distances: vec![f64::INFINITY; num_slots],
+        };
+        dag.path_counts[source_node_id] = 1.0;
+        dag.distances[source_node_id] = 0.0;
+
+        dag
+    }
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Fail with `InvalidArgument` if `cost_fn` gives any connection a NaN or negative cost, or a
+    /// zero cost unless `allow_zero`. Self connections are not checked.
+    pub(crate) fn costs_check<CostFn>(
+        &self,
+        cost_fn: &CostFn,
+        allow_zero: bool,
+    ) -> Result<(), VeloxGraphError>
+    where
+        CostFn: Fn(&ConnectionDataT) -> f64,
+    {
+        for (node_id, node) in self.nodes() {
+            for connection in node.connections_forward.data() {
+                if connection.node_id() == node_id {
+                    continue;
+                }
+
+                let cost = cost_fn(&connection.data);
+                if cost.is_nan() || cost < 0.0 || (cost == 0.0 && !allow_zero) {
+                    return Err(VeloxGraphError::InvalidArgument(format!(
+                        "connection cost {} from node {} to node {} is not {}",
+                        cost,
+                        node_id,
+                        connection.node_id(),
+                        if allow_zero {
+                            "non-negative"
+                        } else {
+                            "positive"
+                        }
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Shortest path DAG from `source_node_id`, counting every connection as 1 hop.
+    pub(crate) fn shortest_path_dag_unweighted(&self, source_node_id: usize) -> ShortestPathDag {
+        let mut dag = ShortestPathDag::new(self.nodes_vector.len(), source_node_id);
+        let mut queue = VecDeque::from([source_node_id]);
+
+        while let Some(node_id) = queue.pop_front() {
+            dag.order.push(node_id);
+            let next_distance = dag.distances[node_id] + 1.0;
+
+            for connection_node_id in self.forward_ids(node_id) {
+                if dag.distances[connection_node_id].is_infinite() {
+                    dag.distances[connection_node_id] = next_distance;
+                    queue.push_back(connection_node_id);
+                }
+
+                if dag.distances[connection_node_id] == next_distance {
+                    dag.path_counts[connection_node_id] += dag.path_counts[node_id];
+                    dag.predecessors[connection_node_id].push(node_id);
+                }
+            }
+        }
+
+        dag
+    }
+
+    /// Shortest path DAG from `source_node_id`, with connection costs from `cost_fn`.
+    ///
+    /// Costs must be non-negative. Self connections are ignored. Predecessors and path counts
+    /// are only complete when costs are positive, since a tie through a zero cost connection can
+    /// reach a node after it is finalized.
+    pub(crate) fn shortest_path_dag_weighted<CostFn>(
+        &self,
+        source_node_id: usize,
+        cost_fn: &CostFn,
+    ) -> ShortestPathDag
+    where
+        CostFn: Fn(&ConnectionDataT) -> f64,
+    {
+        let mut dag = ShortestPathDag::new(self.nodes_vector.len(), source_node_id);
+        let mut finalized = vec![false; self.nodes_vector.len()];
+        let mut heap = BinaryHeap::from([MinCostEntry {
+            cost: 0.0,
+            node_id: source_node_id,
+        }]);
+
+        while let Some(MinCostEntry { cost, node_id }) = heap.pop() {
+            if finalized[node_id] || cost > dag.distances[node_id] {
+                continue;
+            }
+            finalized[node_id] = true;
+            dag.order.push(node_id);
+
+            let Ok(node) = self.node_ref(node_id) else {
+                continue;
+            };
+            for connection in node.connections_forward.data() {
+                let connection_node_id = connection.node_id();
+                if connection_node_id == node_id || finalized[connection_node_id] {
+                    continue;
+                }
+
+                let next_distance = cost + cost_fn(&connection.data);
+                let known_distance = dag.distances[connection_node_id];
+                if next_distance < known_distance {
+                    dag.distances[connection_node_id] = next_distance;
+                    dag.path_counts[connection_node_id] = dag.path_counts[node_id];
+                    dag.predecessors[connection_node_id].clear();
+                    dag.predecessors[connection_node_id].push(node_id);
+                    heap.push(MinCostEntry {
+                        cost: next_distance,
+                        node_id: connection_node_id,
+                    });
+                } else if next_distance == known_distance {
+                    dag.path_counts[connection_node_id] += dag.path_counts[node_id];
+                    dag.predecessors[connection_node_id].push(node_id);
+                }
+            }
+        }
+
+        dag
+    }
+}
@@ -0,0 +1,69 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::graph_trait::Graph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::VecDeque;
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Order the nodes so every connection goes from an earlier node to a later one.
+    ///
+    /// Kahn's algorithm, starting from the nodes without `connections_backward`. Returns
+    /// `CycleDetected` with a node on some cycle if the graph is not acyclic. A self connection
+    /// counts as a cycle.
+    pub(crate) fn topological_order(&self) -> Result<Vec<usize>, VeloxGraphError> {
+        let mut in_degrees = vec![0; self.nodes_vector.len()];
+        for (node_id, node) in self.nodes() {
+            in_degrees[node_id] = node.connections_backward.data().len();
+        }
+
+        let mut queue: VecDeque<usize> = self
+            .node_ids()
+            .filter(|&node_id| in_degrees[node_id] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.num_entries());
+
+        while let Some(node_id) = queue.pop_front() {
+            order.push(node_id);
+            for connection_node_id in self.forward_ids(node_id) {
+                in_degrees[connection_node_id] -= 1;
+                if in_degrees[connection_node_id] == 0 {
+                    queue.push_back(connection_node_id);
+                }
+            }
+        }
+
+        if order.len() == self.num_entries() {
+            return Ok(order);
+        }
+
+        // INFO: every node left over has a left over predecessor, so walking backward through
+        // them must eventually repeat a node, and that node is on a cycle.
+        let mut on_walk = vec![false; self.nodes_vector.len()];
+        let mut node_id = self
+            .node_ids()
+            .find(|&node_id| in_degrees[node_id] > 0)
+            .unwrap_or_default();
+        while !on_walk[node_id] {
+            on_walk[node_id] = true;
+            node_id = self
+                .backward_ids(node_id)
+                .find(|&predecessor_id| in_degrees[predecessor_id] > 0)
+                .unwrap_or(node_id);
+        }
+
+        Err(VeloxGraphError::CycleDetected(node_id))
+    }
+}
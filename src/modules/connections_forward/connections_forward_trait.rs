@@ -14,9 +14,16 @@ where
     NodeIdT: UnsignedInt,
     ConnectionDataT: Clone + Serialize + DeserializeOwned,
 {
+    /// True when `ConnectionsForward::contains` runs in constant time.
+    const CONSTANT_TIME_CONTAINS: bool;
+
     fn new() -> Self;
     fn set(&mut self, node_id_value: usize, connection_data: ConnectionDataT);
     fn remove(&mut self, node_id_value: usize);
+    /// Node id and mutable data of every connection, in the order of `data()`.
+    fn connections_mut<'a>(&'a mut self) -> impl Iterator<Item = (usize, &'a mut ConnectionDataT)>
+    where
+        ConnectionDataT: 'a;
 }
 
 pub trait ConnectionsForward<NodeIdT, ConnectionDataT>:
@@ -26,6 +33,8 @@ where
     ConnectionDataT: Clone + Serialize + DeserializeOwned,
 {
     fn data(&self) -> &Vec<ForwardConnection<NodeIdT, ConnectionDataT>>;
+    fn contains(&self, node_id: usize) -> bool;
+    fn position(&self, node_id: usize) -> Option<usize>;
     fn get<'a>(
         &'a mut self,
         node_id: usize,
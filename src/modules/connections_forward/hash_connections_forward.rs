@@ -35,6 +35,8 @@ where
     NodeIdT: UnsignedInt,
     ConnectionDataT: Clone + Serialize + DeserializeOwned,
 {
+    const CONSTANT_TIME_CONTAINS: bool = true;
+
     // fn data(&self) -> &Vec<ForwardConnection<NodeIdT, ConnectionDataT>> {
     //     &self.data
     // }
@@ -107,6 +109,15 @@ where
             self.lookup_hash.remove(&node_id_value);
         }
     }
+
+    fn connections_mut<'a>(&'a mut self) -> impl Iterator<Item = (usize, &'a mut ConnectionDataT)>
+    where
+        ConnectionDataT: 'a,
+    {
+        self.data
+            .iter_mut()
+            .map(|connection| (connection.node_id.to_usize(), &mut connection.data))
+    }
 }
 
 impl<NodeIdT, ConnectionDataT> ConnectionsForward<NodeIdT, ConnectionDataT>
@@ -119,6 +130,20 @@ where
         &self.data
     }
 
+    /// Check if there is a FORWARD connection to this node. Uses the lookup hash.
+    fn contains(&self, node_id: usize) -> bool {
+        let node_id_generic = NodeIdT::from_usize(node_id);
+        self.lookup_hash.contains_key(&node_id_generic)
+    }
+
+    /// Get the index in `data()` of the FORWARD connection to this node. Uses the lookup hash.
+    fn position(&self, node_id: usize) -> Option<usize> {
+        let node_id_generic = NodeIdT::from_usize(node_id);
+        self.lookup_hash
+            .get(&node_id_generic)
+            .map(|connection_index| connection_index.to_usize())
+    }
+
     /// Get immutable access to a ONE FORWARD connection
     ///
     /// # Example
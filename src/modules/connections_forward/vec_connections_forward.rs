@@ -33,6 +33,8 @@ where
     NodeIdT: UnsignedInt,
     ConnectionDataT: Clone + Serialize + DeserializeOwned,
 {
+    const CONSTANT_TIME_CONTAINS: bool = false;
+
     fn new() -> Self {
         Self { data: Vec::new() }
     }
@@ -67,6 +69,15 @@ where
             self.data.swap_remove(index);
         };
     }
+
+    fn connections_mut<'a>(&'a mut self) -> impl Iterator<Item = (usize, &'a mut ConnectionDataT)>
+    where
+        ConnectionDataT: 'a,
+    {
+        self.data
+            .iter_mut()
+            .map(|connection| (connection.node_id.to_usize(), &mut connection.data))
+    }
 }
 
 impl<NodeIdT, ConnectionDataT> ConnectionsForward<NodeIdT, ConnectionDataT>
@@ -79,6 +90,18 @@ where
         &self.data
     }
 
+    /// Check if there is a FORWARD connection to this node. Scans the connections.
+    fn contains(&self, node_id: usize) -> bool {
+        let node_id = NodeIdT::from_usize(node_id);
+        self.data.iter().any(|item| item.node_id == node_id)
+    }
+
+    /// Get the index in `data()` of the FORWARD connection to this node. Scans the connections.
+    fn position(&self, node_id: usize) -> Option<usize> {
+        let node_id = NodeIdT::from_usize(node_id);
+        self.data.iter().position(|item| item.node_id == node_id)
+    }
+
     /// Get immutable access to a ONE FORWARD connection
     ///
     /// # Example
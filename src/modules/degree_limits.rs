@@ -0,0 +1,344 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::graph_trait::Graph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Largest number of forward and backward connections a node may have. `None` means no limit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DegreeLimit {
+    pub max_forward: Option<usize>,
+    pub max_backward: Option<usize>,
+}
+
+/// Ordering used by `EvictionPolicy::EvictWeakest`.
+pub type ConnectionOrdering<ConnectionDataT> =
+    Arc<dyn Fn(&ConnectionDataT, &ConnectionDataT) -> Ordering + Send + Sync>;
+
+/// What `nodes_connection_set` does when a new connection would go over a degree limit.
+#[derive(Clone)]
+pub enum EvictionPolicy<ConnectionDataT> {
+    /// Fail with `DegreeLimitReached` and leave the graph unchanged.
+    Reject,
+    /// Remove the existing connections that come first in this ordering, i.e. the weakest.
+    EvictWeakest(ConnectionOrdering<ConnectionDataT>),
+    /// Remove the existing connections that were set first. Connections set before the limits
+    /// were enabled count as the oldest.
+    EvictOldest,
+}
+
+impl<ConnectionDataT> EvictionPolicy<ConnectionDataT> {
+    /// `EvictWeakest` with any ordering, including closures that capture state.
+    pub fn evict_weakest<OrderingFn>(ordering: OrderingFn) -> Self
+    where
+        OrderingFn: Fn(&ConnectionDataT, &ConnectionDataT) -> Ordering + Send + Sync + 'static,
+    {
+        Self::EvictWeakest(Arc::new(ordering))
+    }
+}
+
+impl<ConnectionDataT> fmt::Debug for EvictionPolicy<ConnectionDataT> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reject => formatter.write_str("Reject"),
+            Self::EvictWeakest(_) => formatter.write_str("EvictWeakest(..)"),
+            Self::EvictOldest => formatter.write_str("EvictOldest"),
+        }
+    }
+}
+
+/// Degree limits of a graph, checked by `nodes_connection_set` while enabled.
+pub(crate) struct DegreeLimits<ConnectionDataT> {
+    default_limit: DegreeLimit,
+    node_limits: HashMap<usize, DegreeLimit>,
+    policy: EvictionPolicy<ConnectionDataT>,
+    num_connections_set: u64,
+    ages: HashMap<(usize, usize), u64>,
+}
+
+impl<ConnectionDataT> DegreeLimits<ConnectionDataT> {
+    fn new(default_limit: DegreeLimit, policy: EvictionPolicy<ConnectionDataT>) -> Self {
+        Self {
+            default_limit,
+            node_limits: HashMap::new(),
+            policy,
+            num_connections_set: 0,
+            ages: HashMap::new(),
+        }
+    }
+
+    fn limit(&self, node_id: usize) -> DegreeLimit {
+        self.node_limits
+            .get(&node_id)
+            .copied()
+            .unwrap_or(self.default_limit)
+    }
+
+    pub(crate) fn connection_set(&mut self, first_node_id: usize, second_node_id: usize) {
+        // INFO: ages start at 1, so connections from before the limits sort first with age 0.
+        if let Entry::Vacant(entry) = self.ages.entry((first_node_id, second_node_id)) {
+            self.num_connections_set += 1;
+            entry.insert(self.num_connections_set);
+        }
+    }
+
+    pub(crate) fn connection_removed(&mut self, first_node_id: usize, second_node_id: usize) {
+        self.ages.remove(&(first_node_id, second_node_id));
+    }
+
+    pub(crate) fn node_deleted(&mut self, node_id: usize) {
+        self.node_limits.remove(&node_id);
+        self.ages.retain(|&(first_node_id, second_node_id), _| {
+            first_node_id != node_id && second_node_id != node_id
+        });
+    }
+}
+
+/// Number of connections to remove so one more fits under `max`.
+fn excess(num_connections: usize, max: Option<usize>) -> usize {
+    max.map_or(0, |max| (num_connections + 1).saturating_sub(max))
+}
+
+/// Fail if `policy` cannot make room for one more connection next to `num_connections`. A limit
+/// of 0 has nothing to evict.
+fn room_check<ConnectionDataT>(
+    policy: &EvictionPolicy<ConnectionDataT>,
+    node_id: usize,
+    num_connections: usize,
+    max: Option<usize>,
+) -> Result<(), VeloxGraphError> {
+    let num_excess = excess(num_connections, max);
+    if num_excess > 0 && (matches!(policy, EvictionPolicy::Reject) || num_excess > num_connections)
+    {
+        return Err(VeloxGraphError::DegreeLimitReached(node_id));
+    }
+
+    Ok(())
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Limit the number of connections of every node, enforced by `nodes_connection_set`.
+    ///
+    /// `default_limit` applies to every node without its own limit from `degree_limit_set`. Only
+    /// new connections are checked, so overwriting the data of an existing connection always
+    /// works. When a new connection would go over a limit, `policy` either rejects it or removes
+    /// existing connections of the node that is full. Removal goes through
+    /// `nodes_connection_remove`, so the forward and backward lists stay in sync. Nodes that are
+    /// already over their limit lose enough connections on their next new one. Calling this
+    /// again replaces the limits and policy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::degree_limits::{DegreeLimit, EvictionPolicy};
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::ConnectionsForward;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Every node keeps its two strongest incoming connections.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    /// graph.degree_limits_enable(
+    ///     DegreeLimit {
+    ///         max_forward: None,
+    ///         max_backward: Some(2),
+    ///     },
+    ///     EvictionPolicy::evict_weakest(|first: &f64, second: &f64| first.total_cmp(second)),
+    /// );
+    ///
+    /// for index in 0..4 {
+    ///     graph.node_create(index);
+    /// }
+    /// graph.nodes_connection_set(0, 3, 0.9).unwrap();
+    /// graph.nodes_connection_set(1, 3, 0.1).unwrap();
+    ///
+    /// // INFO: The weakest connection, 1 -> 3, makes room.
+    /// graph.nodes_connection_set(2, 3, 0.5).unwrap();
+    ///
+    /// assert_eq!(graph.node_get(1).unwrap().connections_forward().data().len(), 0);
+    /// assert_eq!(graph.node_get(2).unwrap().connections_forward().data().len(), 1);
+    /// ```
+    pub fn degree_limits_enable(
+        &mut self,
+        default_limit: DegreeLimit,
+        policy: EvictionPolicy<ConnectionDataT>,
+    ) {
+        self.degree_limits = Some(DegreeLimits::new(default_limit, policy));
+    }
+
+    /// Stop enforcing degree limits and forget them.
+    pub fn degree_limits_disable(&mut self) {
+        self.degree_limits = None;
+    }
+
+    /// Check if degree limits are enabled.
+    pub fn degree_limits_enabled(&self) -> bool {
+        self.degree_limits.is_some()
+    }
+
+    /// Give one node its own limit instead of the default. Dropped when the node is deleted.
+    pub fn degree_limit_set(
+        &mut self,
+        node_id: usize,
+        limit: DegreeLimit,
+    ) -> Result<(), VeloxGraphError> {
+        self.node_ref(node_id)?;
+        let limits = self
+            .degree_limits
+            .as_mut()
+            .ok_or(VeloxGraphError::DegreeLimitsDisabled)?;
+
+        limits.node_limits.insert(node_id, limit);
+
+        Ok(())
+    }
+
+    /// Get the limit that applies to a node.
+    pub fn degree_limit(&self, node_id: usize) -> Result<DegreeLimit, VeloxGraphError> {
+        self.node_ref(node_id)?;
+        let limits = self
+            .degree_limits
+            .as_ref()
+            .ok_or(VeloxGraphError::DegreeLimitsDisabled)?;
+
+        Ok(limits.limit(node_id))
+    }
+
+    /// Apply the eviction policy so a new connection from `first_node_id` to `second_node_id`
+    /// fits under both limits. Does nothing if limits are disabled or the connection exists.
+    pub(crate) fn degree_limits_make_room(
+        &mut self,
+        first_node_id: usize,
+        second_node_id: usize,
+    ) -> Result<(), VeloxGraphError> {
+        let Some(limits) = &self.degree_limits else {
+            return Ok(());
+        };
+        if self.has_connection(first_node_id, second_node_id) {
+            return Ok(());
+        }
+
+        let forward: Vec<(usize, usize)> = self
+            .forward_ids(first_node_id)
+            .map(|node_id| (first_node_id, node_id))
+            .collect();
+        let backward: Vec<(usize, usize)> = self
+            .backward_ids(second_node_id)
+            .map(|node_id| (node_id, second_node_id))
+            .collect();
+        let max_forward = limits.limit(first_node_id).max_forward;
+        let max_backward = limits.limit(second_node_id).max_backward;
+
+        // INFO: fail before removing anything.
+        room_check(&limits.policy, first_node_id, forward.len(), max_forward)?;
+        room_check(&limits.policy, second_node_id, backward.len(), max_backward)?;
+
+        let forward_excess = excess(forward.len(), max_forward);
+        let backward_excess = excess(backward.len(), max_backward);
+
+        let mut victims = self.degree_limits_victims(limits, forward, forward_excess);
+        victims.extend(self.degree_limits_victims(limits, backward, backward_excess));
+        for (victim_first_node_id, victim_second_node_id) in victims {
+            self.nodes_connection_remove(victim_first_node_id, victim_second_node_id)?;
+        }
+
+        Ok(())
+    }
+
+    /// Fail if the connection from `first_node_id` to `second_node_id` cannot be replaced by a
+    /// new node in between, i.e. if one of the two connections through that node would be
+    /// rejected once the original connection is gone. Does nothing if limits are disabled.
+    pub(crate) fn degree_limits_split_check(
+        &self,
+        first_node_id: usize,
+        second_node_id: usize,
+    ) -> Result<(), VeloxGraphError> {
+        let Some(limits) = &self.degree_limits else {
+            return Ok(());
+        };
+
+        // INFO: the new node takes the next free slot and starts with the default limit.
+        let new_node_id = self
+            .empty_slots
+            .last()
+            .copied()
+            .unwrap_or(self.nodes_vector.len());
+        let num_forward = self.forward_ids(first_node_id).count() - 1;
+        let num_backward = self.backward_ids(second_node_id).count() - 1;
+
+        room_check(
+            &limits.policy,
+            first_node_id,
+            num_forward,
+            limits.limit(first_node_id).max_forward,
+        )?;
+        room_check(
+            &limits.policy,
+            new_node_id,
+            0,
+            limits.default_limit.max_backward,
+        )?;
+        room_check(
+            &limits.policy,
+            new_node_id,
+            0,
+            limits.default_limit.max_forward,
+        )?;
+        room_check(
+            &limits.policy,
+            second_node_id,
+            num_backward,
+            limits.limit(second_node_id).max_backward,
+        )
+    }
+
+    fn degree_limits_victims(
+        &self,
+        limits: &DegreeLimits<ConnectionDataT>,
+        mut candidates: Vec<(usize, usize)>,
+        num_excess: usize,
+    ) -> Vec<(usize, usize)> {
+        if num_excess == 0 {
+            return Vec::new();
+        }
+
+        match &limits.policy {
+            EvictionPolicy::Reject => {}
+            EvictionPolicy::EvictWeakest(ordering) => {
+                let mut weighted: Vec<((usize, usize), &ConnectionDataT)> = candidates
+                    .iter()
+                    .filter_map(|&(first_node_id, second_node_id)| {
+                        let connection_data =
+                            self.connection_data(first_node_id, second_node_id)?;
+                        Some(((first_node_id, second_node_id), connection_data))
+                    })
+                    .collect();
+                weighted
+                    .sort_by(|(_, first_data), (_, second_data)| ordering(first_data, second_data));
+                candidates = weighted.into_iter().map(|(pair, _)| pair).collect();
+            }
+            EvictionPolicy::EvictOldest => {
+                candidates.sort_by_key(|pair| limits.ages.get(pair).copied().unwrap_or(0));
+            }
+        }
+
+        candidates.truncate(num_excess);
+        candidates
+    }
+}
@@ -25,6 +25,24 @@ pub enum VeloxGraphError {
     SlotNotUsed(usize),
     #[error("database: Connection {0} is not set. No data here.")]
     ConnectionNotSet(usize),
+    #[error(
+        "database: Component tracking is not enabled. Call component_tracking_enable() first."
+    )]
+    ComponentTrackingDisabled,
+    #[error(
+        "database: Reachability index is not enabled. Call reachability_index_enable() first."
+    )]
+    ReachabilityIndexDisabled,
+    #[error("database: Degree limits are not enabled. Call degree_limits_enable() first.")]
+    DegreeLimitsDisabled,
+    #[error("database: Node {0} has reached its connection limit.")]
+    DegreeLimitReached(usize),
+    #[error("algorithm: Invalid argument: {0}")]
+    InvalidArgument(String),
+    #[error("algorithm: Did not converge within {0} iterations")]
+    DidNotConverge(usize),
+    #[error("algorithm: Graph is not acyclic. Node {0} is part of a cycle.")]
+    CycleDetected(usize),
 
     #[error("unknown database error")]
     Unknown,
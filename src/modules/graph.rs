@@ -1,3 +1,5 @@
+use crate::modules::algorithms::components::ComponentTracker;
+use crate::modules::algorithms::reachability::ReachabilityIndex;
 use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
 use crate::modules::connections_backward::{
     hash_connections_backward::HashConnectionsBackward,
@@ -8,9 +10,11 @@ use crate::modules::connections_forward::{
     hash_connections_forward::HashConnectionsForward,
     vec_connections_forward::VecConnectionsForward,
 };
+use crate::modules::degree_limits::DegreeLimits;
 use crate::modules::error::VeloxGraphError;
 use crate::modules::graph_settings::VeloxGraghSettings;
 use crate::modules::graph_trait::{graph_private::GraphSealed, Graph, GraphInternal};
+use crate::modules::neural::innovation::InnovationIds;
 use crate::modules::node::Node;
 use crate::modules::unsigned_int::UnsignedInt;
 
@@ -111,6 +115,12 @@ where
         Vec<Option<Node<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>>>,
     pub(crate) empty_slots: Vec<usize>,
 
+    // INFO: optional incremental state. Not saved to disk.
+    pub(crate) component_tracker: Option<ComponentTracker>,
+    pub(crate) reachability_index: Option<ReachabilityIndex>,
+    pub(crate) innovation_ids: Option<InnovationIds>,
+    pub(crate) degree_limits: Option<DegreeLimits<ConnectionDataT>>,
+
     // PhantomData to "use" the other generics.
     _phantom_id: PhantomData<NodeIdT>,
     _phantom_node_data: PhantomData<NodeDataT>,
@@ -202,6 +212,11 @@ where
             nodes_vector: Vec::new(),
             empty_slots: Vec::new(),
 
+            component_tracker: None,
+            reachability_index: None,
+            innovation_ids: None,
+            degree_limits: None,
+
             _phantom_id: PhantomData,
             _phantom_node_data: PhantomData,
             _phantom_conn_data: PhantomData,
@@ -253,6 +268,13 @@ where
             node.node_id = new_node_id_generic;
         }
 
+        if let Some(tracker) = &mut self.component_tracker {
+            tracker.node_created(new_node_id);
+        }
+        if let Some(index) = &mut self.reachability_index {
+            index.node_created(new_node_id);
+        }
+
         new_node_id
     }
 
@@ -363,6 +385,20 @@ where
         }
 
         self.num_entries -= 1;
+
+        if let Some(tracker) = &mut self.component_tracker {
+            tracker.invalidate();
+        }
+        if let Some(index) = &mut self.reachability_index {
+            index.invalidate();
+        }
+        if let Some(innovation_ids) = &mut self.innovation_ids {
+            innovation_ids.node_deleted(node_id_to_delete);
+        }
+        if let Some(limits) = &mut self.degree_limits {
+            limits.node_deleted(node_id_to_delete);
+        }
+
         Ok(())
     }
 
@@ -400,8 +436,10 @@ where
     ) -> Result<(), VeloxGraphError> {
         // INFO: check if both nodes exist, then create connection.
         let _second_node = self.node_get(second_node_id)?;
-        let first_node = self.node_get(first_node_id)?;
+        let _first_node = self.node_get(first_node_id)?;
+        self.degree_limits_make_room(first_node_id, second_node_id)?;
 
+        let first_node = self.node_get(first_node_id)?;
         first_node
             .connections_forward()
             .set(second_node_id, connection_data);
@@ -409,6 +447,16 @@ where
         let second_node = self.node_get(second_node_id)?;
         second_node.connections_backward.create(first_node_id);
 
+        if let Some(tracker) = &mut self.component_tracker {
+            tracker.nodes_connected(first_node_id, second_node_id);
+        }
+        if let Some(index) = &mut self.reachability_index {
+            index.nodes_connected(first_node_id, second_node_id);
+        }
+        if let Some(limits) = &mut self.degree_limits {
+            limits.connection_set(first_node_id, second_node_id);
+        }
+
         Ok(())
     }
 
@@ -456,6 +504,19 @@ where
         let second_node = self.node_get(second_node_id)?;
         second_node.connections_backward.delete(first_node_id);
 
+        if let Some(tracker) = &mut self.component_tracker {
+            tracker.invalidate();
+        }
+        if let Some(index) = &mut self.reachability_index {
+            index.invalidate();
+        }
+        if let Some(innovation_ids) = &mut self.innovation_ids {
+            innovation_ids.connection_removed(first_node_id, second_node_id);
+        }
+        if let Some(limits) = &mut self.degree_limits {
+            limits.connection_removed(first_node_id, second_node_id);
+        }
+
         Ok(())
     }
 
@@ -581,3 +642,124 @@ where
         Ok(new_graph)
     }
 }
+
+// INFO: Read-only helpers shared by the algorithm modules.
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Ids of every used slot in `nodes_vector`, in ascending order.
+    pub(crate) fn node_ids(&self) -> impl Iterator<Item = usize> + '_ {
+        self.nodes_vector
+            .iter()
+            .enumerate()
+            .filter_map(|(node_id, node_option)| node_option.as_ref().map(|_| node_id))
+    }
+
+    /// Every used node along with its id, in ascending id order.
+    pub(crate) fn nodes(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            usize,
+            &Node<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>,
+        ),
+    > + '_ {
+        self.nodes_vector
+            .iter()
+            .enumerate()
+            .filter_map(|(node_id, node_option)| node_option.as_ref().map(|node| (node_id, node)))
+    }
+
+    /// Immutable version of `node_get`.
+    pub(crate) fn node_ref(
+        &self,
+        node_id: usize,
+    ) -> Result<
+        &Node<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>,
+        VeloxGraphError,
+    > {
+        match self.nodes_vector.get(node_id) {
+            Some(Some(node)) => Ok(node),
+            Some(None) => Err(VeloxGraphError::SlotNotUsed(node_id)),
+            None => Err(VeloxGraphError::SlotNotAllocated(node_id)),
+        }
+    }
+
+    /// Node ids this node connects forward to. Empty if the slot is not used.
+    pub(crate) fn forward_ids(&self, node_id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.node_ref(node_id).into_iter().flat_map(|node| {
+            node.connections_forward
+                .data()
+                .iter()
+                .map(|connection| connection.node_id())
+        })
+    }
+
+    /// Node ids that connect forward to this node. Empty if the slot is not used.
+    pub(crate) fn backward_ids(&self, node_id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.node_ref(node_id).into_iter().flat_map(|node| {
+            node.connections_backward
+                .data()
+                .iter()
+                .map(|connection| connection.node_id())
+        })
+    }
+
+    /// Every connection as a (first node id, second node id) pair, in ascending order of the
+    /// first node id.
+    pub(crate) fn connection_pairs(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.nodes().flat_map(|(first_node_id, node)| {
+            node.connections_forward
+                .data()
+                .iter()
+                .map(move |connection| (first_node_id, connection.node_id()))
+        })
+    }
+
+    /// Check if `first_node_id` connects forward to `second_node_id`.
+    pub(crate) fn has_connection(&self, first_node_id: usize, second_node_id: usize) -> bool {
+        self.node_ref(first_node_id)
+            .is_ok_and(|node| node.connections_forward.contains(second_node_id))
+    }
+
+    /// Data of the connection from `first_node_id` to `second_node_id`, if it is set.
+    pub(crate) fn connection_data(
+        &self,
+        first_node_id: usize,
+        second_node_id: usize,
+    ) -> Option<&ConnectionDataT> {
+        let connections = &self.node_ref(first_node_id).ok()?.connections_forward;
+        let connection_index = connections.position(second_node_id)?;
+
+        Some(&connections.data()[connection_index].data)
+    }
+
+    /// Neighbors of a node ignoring direction, sorted by node id, without self connections.
+    ///
+    /// Each neighbor comes with its multiplicity: 2 if connected both ways, otherwise 1.
+    pub(crate) fn undirected_neighbors(&self, node_id: usize) -> Vec<(usize, u8)> {
+        let mut neighbor_ids: Vec<usize> = self
+            .forward_ids(node_id)
+            .chain(self.backward_ids(node_id))
+            .filter(|&neighbor_id| neighbor_id != node_id)
+            .collect();
+        neighbor_ids.sort_unstable();
+
+        let mut neighbors: Vec<(usize, u8)> = Vec::with_capacity(neighbor_ids.len());
+        for neighbor_id in neighbor_ids {
+            match neighbors.last_mut() {
+                Some((last_id, multiplicity)) if *last_id == neighbor_id => *multiplicity += 1,
+                _ => neighbors.push((neighbor_id, 1)),
+            }
+        }
+
+        neighbors
+    }
+}
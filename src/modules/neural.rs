@@ -0,0 +1,9 @@
+pub mod aggregation;
+pub mod crossover;
+pub mod gradients;
+pub mod innovation;
+pub mod mutation;
+pub mod normalization;
+pub mod propagation;
+pub mod recurrent;
+pub mod spiking;
@@ -0,0 +1,196 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// How the feature vectors of a node's neighbors are combined.
+///
+/// Each neighbor vector is first scaled by the weight of the connection to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aggregation {
+    /// Element wise sum.
+    Sum,
+    /// Element wise mean.
+    Mean,
+    /// Element wise maximum.
+    Max,
+    /// Sum weighted by a softmax over the neighbors of the scaled dot product between the
+    /// node's own features and each neighbor's features.
+    Attention,
+}
+
+/// Which connections of a node are used, e.g. which neighbors are aggregated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NeighborDirection {
+    /// Nodes connecting to this node, found through `connections_backward`.
+    Incoming,
+    /// Nodes this node connects to, found through `connections_forward`.
+    Outgoing,
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Combine the feature vectors of every node's neighbors into a new feature vector.
+    ///
+    /// `feature_fn` gets the features stored in each node, and all of them must have the same
+    /// length. `weight_fn` scales each neighbor by its connection. Use `|_| 1.0` to ignore
+    /// connection data. The result is indexed by node id. Nodes without neighbors get a vector of
+    /// zeros, and empty slots get an empty vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::neural::aggregation::{Aggregation, NeighborDirection};
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph. Node data is a feature vector.
+    /// let mut graph: VeloxGraphVec<usize, Vec<f64>, f64> = VeloxGraphVec::new();
+    /// graph.node_create(vec![1.0, 0.0]);
+    /// graph.node_create(vec![3.0, 4.0]);
+    /// graph.node_create(vec![0.0, 0.0]);
+    ///
+    /// // INFO: node2 listens to node0 and node1.
+    /// graph.nodes_connection_set(0, 2, 1.0).unwrap();
+    /// graph.nodes_connection_set(1, 2, 1.0).unwrap();
+    ///
+    /// let features = graph
+    ///     .aggregate_neighbors(
+    ///         Aggregation::Mean,
+    ///         NeighborDirection::Incoming,
+    ///         |features| features.as_slice(),
+    ///         |_| 1.0,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(features[2], vec![2.0, 2.0]);
+    /// assert_eq!(features[0], vec![0.0, 0.0]);
+    /// ```
+    pub fn aggregate_neighbors<FeatureFn, WeightFn>(
+        &self,
+        aggregation: Aggregation,
+        direction: NeighborDirection,
+        feature_fn: FeatureFn,
+        weight_fn: WeightFn,
+    ) -> Result<Vec<Vec<f64>>, VeloxGraphError>
+    where
+        FeatureFn: Fn(&NodeDataT) -> &[f64],
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        let mut num_features = None;
+        for (node_id, node) in self.nodes() {
+            let node_num_features = feature_fn(&node.data).len();
+            match num_features {
+                None => num_features = Some(node_num_features),
+                Some(expected) if expected != node_num_features => {
+                    return Err(VeloxGraphError::InvalidArgument(format!(
+                        "node {} has {} features, expected {}",
+                        node_id, node_num_features, expected
+                    )));
+                }
+                Some(_) => {}
+            }
+        }
+        let num_features = num_features.unwrap_or(0);
+        let scale = 1.0 / (num_features.max(1) as f64).sqrt();
+
+        let mut aggregated = vec![Vec::new(); self.nodes_vector.len()];
+        let mut neighbors: Vec<(&[f64], f64)> = Vec::new();
+
+        for (node_id, node) in self.nodes() {
+            // INFO: gather (features, connection weight) of every neighbor.
+            neighbors.clear();
+            match direction {
+                NeighborDirection::Outgoing => {
+                    for connection in node.connections_forward.data() {
+                        let neighbor = self.node_ref(connection.node_id())?;
+                        neighbors.push((feature_fn(&neighbor.data), weight_fn(&connection.data)));
+                    }
+                }
+                NeighborDirection::Incoming => {
+                    for connection in node.connections_backward.data() {
+                        let neighbor_id = connection.node_id();
+                        let neighbor = self.node_ref(neighbor_id)?;
+                        let Some(connection_data) = self.connection_data(neighbor_id, node_id)
+                        else {
+                            continue;
+                        };
+                        neighbors.push((feature_fn(&neighbor.data), weight_fn(connection_data)));
+                    }
+                }
+            }
+
+            let mut result = vec![0.0; num_features];
+            if neighbors.is_empty() {
+                aggregated[node_id] = result;
+                continue;
+            }
+
+            match aggregation {
+                Aggregation::Sum | Aggregation::Mean => {
+                    for &(features, weight) in &neighbors {
+                        for (total, feature) in result.iter_mut().zip(features) {
+                            *total += weight * feature;
+                        }
+                    }
+                    if aggregation == Aggregation::Mean {
+                        let num_neighbors = neighbors.len() as f64;
+                        result.iter_mut().for_each(|total| *total /= num_neighbors);
+                    }
+                }
+                Aggregation::Max => {
+                    result.fill(f64::NEG_INFINITY);
+                    for &(features, weight) in &neighbors {
+                        for (maximum, feature) in result.iter_mut().zip(features) {
+                            *maximum = maximum.max(weight * feature);
+                        }
+                    }
+                }
+                Aggregation::Attention => {
+                    let own_features = feature_fn(&node.data);
+                    let scores: Vec<f64> = neighbors
+                        .iter()
+                        .map(|(features, _)| {
+                            scale
+                                * own_features
+                                    .iter()
+                                    .zip(*features)
+                                    .map(|(own, other)| own * other)
+                                    .sum::<f64>()
+                        })
+                        .collect();
+
+                    // INFO: subtract the largest score so the exponentials cannot overflow.
+                    let max_score = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                    let exponentials: Vec<f64> = scores
+                        .iter()
+                        .map(|score| (score - max_score).exp())
+                        .collect();
+                    let total: f64 = exponentials.iter().sum();
+
+                    for (&(features, weight), exponential) in neighbors.iter().zip(&exponentials) {
+                        let attention = exponential / total;
+                        for (sum, feature) in result.iter_mut().zip(features) {
+                            *sum += attention * weight * feature;
+                        }
+                    }
+                }
+            }
+
+            aggregated[node_id] = result;
+        }
+
+        Ok(aggregated)
+    }
+}
@@ -0,0 +1,169 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::graph_trait::Graph;
+use crate::modules::neural::innovation::InnovationIds;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use rand::Rng;
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::HashMap;
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Build a child graph from two parents, aligning their nodes and connections by innovation
+    /// number.
+    ///
+    /// The child has the structure of the fitter parent, or of `parent_a` when both are equally
+    /// fit. Nodes and connections found in both parents take their data from either parent at
+    /// random. The rest comes from the fitter parent, and what only the other parent has is
+    /// dropped. The child keeps the innovation numbers, and its node ids follow the order of the
+    /// fitter parent. Every node and connection of both parents needs an innovation number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::neural::innovation::InnovationRegistry;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Two parents with the same input and output.
+    /// let mut registry = InnovationRegistry::new();
+    /// let input = registry.node_innovation_new();
+    /// let output = registry.node_innovation_new();
+    ///
+    /// let mut parents: Vec<VeloxGraphVec<usize, u32, f64>> = Vec::new();
+    /// for weight in [1.0, 2.0] {
+    ///     let mut graph = VeloxGraphVec::new();
+    ///     graph.node_create(0);
+    ///     graph.node_create(1);
+    ///     graph.node_innovation_set(0, input).unwrap();
+    ///     graph.node_innovation_set(1, output).unwrap();
+    ///     graph.nodes_connection_set(0, 1, weight).unwrap();
+    ///     graph.connection_innovation_set(&mut registry, 0, 1).unwrap();
+    ///     parents.push(graph);
+    /// }
+    ///
+    /// // INFO: The fitter parent also has a self connection on its output.
+    /// parents[1].nodes_connection_set(1, 1, 3.0).unwrap();
+    /// parents[1].connection_innovation_set(&mut registry, 1, 1).unwrap();
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let child =
+    ///     VeloxGraphVec::crossover(&parents[0], &parents[1], 0.2, 0.9, &mut rng).unwrap();
+    ///
+    /// assert_eq!(child.num_entries(), 2);
+    /// assert!(child.connection_innovation(1, 1).is_some());
+    /// assert_eq!(child.connection_innovation(0, 1), parents[0].connection_innovation(0, 1));
+    /// ```
+    pub fn crossover<RngT>(
+        parent_a: &Self,
+        parent_b: &Self,
+        fitness_a: f64,
+        fitness_b: f64,
+        rng: &mut RngT,
+    ) -> Result<Self, VeloxGraphError>
+    where
+        RngT: Rng,
+    {
+        let (fitter, other) = match fitness_b > fitness_a {
+            true => (parent_b, parent_a),
+            false => (parent_a, parent_b),
+        };
+
+        let other_node_ids = other.node_ids_by_innovation()?;
+        let mut other_connections: HashMap<usize, (usize, usize)> = HashMap::new();
+        for (first_node_id, second_node_id) in other.connection_pairs() {
+            let innovation = other.connection_innovation_required(first_node_id, second_node_id)?;
+            other_connections.insert(innovation, (first_node_id, second_node_id));
+        }
+
+        let mut child = Self::new();
+        let mut innovation_ids = InnovationIds::default();
+        let mut child_node_ids = vec![usize::MAX; fitter.nodes_vector.len()];
+
+        for (node_id, node) in fitter.nodes() {
+            let innovation = fitter.node_innovation_required(node_id)?;
+            let node_data = match other_node_ids.get(&innovation) {
+                Some(&other_node_id) if rng.random_bool(0.5) => {
+                    &other.node_ref(other_node_id)?.data
+                }
+                _ => &node.data,
+            };
+
+            let child_node_id = child.node_create(node_data.clone());
+            child_node_ids[node_id] = child_node_id;
+            innovation_ids.node_set(child_node_id, innovation);
+        }
+
+        for (first_node_id, second_node_id) in fitter.connection_pairs() {
+            let innovation =
+                fitter.connection_innovation_required(first_node_id, second_node_id)?;
+            let (parent, (parent_first_node_id, parent_second_node_id)) =
+                match other_connections.get(&innovation) {
+                    Some(&other_pair) if rng.random_bool(0.5) => (other, other_pair),
+                    _ => (fitter, (first_node_id, second_node_id)),
+                };
+            let Some(connection_data) =
+                parent.connection_data(parent_first_node_id, parent_second_node_id)
+            else {
+                return Err(VeloxGraphError::ConnectionNotSet(parent_second_node_id));
+            };
+
+            let child_first_node_id = child_node_ids[first_node_id];
+            let child_second_node_id = child_node_ids[second_node_id];
+            child.nodes_connection_set(
+                child_first_node_id,
+                child_second_node_id,
+                connection_data.clone(),
+            )?;
+            innovation_ids
+                .connections
+                .insert((child_first_node_id, child_second_node_id), innovation);
+        }
+
+        child.innovation_ids = Some(innovation_ids);
+
+        Ok(child)
+    }
+
+    fn node_ids_by_innovation(&self) -> Result<HashMap<usize, usize>, VeloxGraphError> {
+        let mut node_ids = HashMap::new();
+        for node_id in self.node_ids() {
+            node_ids.insert(self.node_innovation_required(node_id)?, node_id);
+        }
+
+        Ok(node_ids)
+    }
+
+    fn node_innovation_required(&self, node_id: usize) -> Result<usize, VeloxGraphError> {
+        self.node_innovation(node_id).ok_or_else(|| {
+            VeloxGraphError::InvalidArgument(format!("node {} has no innovation number", node_id))
+        })
+    }
+
+    fn connection_innovation_required(
+        &self,
+        first_node_id: usize,
+        second_node_id: usize,
+    ) -> Result<usize, VeloxGraphError> {
+        self.connection_innovation(first_node_id, second_node_id)
+            .ok_or_else(|| {
+                VeloxGraphError::InvalidArgument(format!(
+                    "connection {} -> {} has no innovation number",
+                    first_node_id, second_node_id
+                ))
+            })
+    }
+}
@@ -0,0 +1,124 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Backward pass through one `propagate` step. Returns the gradient of every input
+    /// activation and hands each connection its weight gradient.
+    ///
+    /// `input_activations` are the activations that were propagated, and `output_gradients` the
+    /// gradients with respect to the summed inputs of each node (before the activation function).
+    /// Both are indexed by node id, and missing entries count as 0.
+    ///
+    /// For every node with a non-zero output gradient, finds the nodes feeding it through
+    /// `connections_backward`, then walks their forward connections once and calls
+    /// `update_fn(connection_data, input_activation * output_gradient)` on each connection into a
+    /// node with a gradient. No connection is looked up by id, so the cost is the same on both
+    /// backends. The input gradient of a node sums `weight_fn(connection) * output_gradient` over its
+    /// connections, using the weights from before `update_fn` runs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphHash;
+    /// use velox_graph::ConnectionsForward;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphHash<usize, u32, f64> = VeloxGraphHash::new();
+    ///
+    /// // INFO: Inputs 0 and 1 both feed node2.
+    /// for index in 0..3 {
+    ///     graph.node_create(index);
+    /// }
+    /// graph.nodes_connection_set(0, 2, 0.5).unwrap();
+    /// graph.nodes_connection_set(1, 2, -2.0).unwrap();
+    ///
+    /// // INFO: One step of gradient descent with learning rate 0.25.
+    /// let input_gradients = graph
+    ///     .backpropagate(
+    ///         &[4.0, 1.0],
+    ///         &[0.0, 0.0, 1.0],
+    ///         |weight| *weight,
+    ///         |weight, gradient| *weight -= 0.25 * gradient,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(input_gradients, vec![0.5, -2.0, 0.0]);
+    ///
+    /// let node0 = graph.node_get(0).unwrap();
+    /// assert_eq!(node0.connections_forward().get(2).unwrap().data, -0.5);
+    /// ```
+    pub fn backpropagate<WeightFn, UpdateFn>(
+        &mut self,
+        input_activations: &[f64],
+        output_gradients: &[f64],
+        weight_fn: WeightFn,
+        mut update_fn: UpdateFn,
+    ) -> Result<Vec<f64>, VeloxGraphError>
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+        UpdateFn: FnMut(&mut ConnectionDataT, f64),
+    {
+        self.activations_check(input_activations)?;
+        self.activations_check(output_gradients)?;
+
+        let mut input_gradients = vec![0.0; self.nodes_vector.len()];
+
+        // INFO: find every node that feeds a node with a gradient, once each.
+        let mut is_source = vec![false; self.nodes_vector.len()];
+        let mut source_node_ids = Vec::new();
+        for (node_id, &output_gradient) in output_gradients.iter().enumerate() {
+            if output_gradient == 0.0 {
+                continue;
+            }
+
+            let Some(Some(node)) = self.nodes_vector.get(node_id) else {
+                continue;
+            };
+            for connection in node.connections_backward.data() {
+                let source_node_id = connection.node_id();
+                if !is_source[source_node_id] {
+                    is_source[source_node_id] = true;
+                    source_node_ids.push(source_node_id);
+                }
+            }
+        }
+
+        // INFO: then walk the forward connections of each source, so no connection is looked up.
+        for source_node_id in source_node_ids {
+            let Some(Some(source_node)) = self.nodes_vector.get_mut(source_node_id) else {
+                continue;
+            };
+            let input_activation = input_activations
+                .get(source_node_id)
+                .copied()
+                .unwrap_or(0.0);
+
+            for (node_id, connection_data) in source_node.connections_forward.connections_mut() {
+                let output_gradient = output_gradients.get(node_id).copied().unwrap_or(0.0);
+                if output_gradient == 0.0 {
+                    continue;
+                }
+
+                input_gradients[source_node_id] += weight_fn(connection_data) * output_gradient;
+                update_fn(connection_data, input_activation * output_gradient);
+            }
+        }
+
+        Ok(input_gradients)
+    }
+}
@@ -0,0 +1,281 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Hands out innovation numbers for the structural changes of a population of graphs.
+///
+/// Share one registry between every graph of a population. The same structural change then
+/// gets the same number in every graph, so genomes can be aligned for crossover. A connection is
+/// identified by the innovation numbers of its two nodes, and a node created by splitting a
+/// connection is identified by the innovation number of that connection. Nodes and connections
+/// count from the same sequence.
+///
+/// The registry is kept by the caller, not by the graphs. It can be serialized on its own, but
+/// the innovation numbers held by each graph are not written by `save`, so a loaded graph has
+/// none and its nodes and connections must be tagged again.
+///
+/// # Example
+///
+/// ```
+/// use velox_graph::neural::innovation::InnovationRegistry;
+///
+/// let mut registry = InnovationRegistry::new();
+/// let input = registry.node_innovation_new();
+/// let output = registry.node_innovation_new();
+///
+/// // INFO: Asking again for the same connection gives the same number.
+/// let connection = registry.connection_innovation(input, output);
+/// assert_eq!(registry.connection_innovation(input, output), connection);
+/// assert_eq!(registry.num_innovations(), 3);
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct InnovationRegistry {
+    num_innovations: usize,
+    connections: HashMap<(usize, usize), usize>,
+    // INFO: every node number handed out for splitting a connection, oldest first.
+    splits: HashMap<usize, Vec<usize>>,
+}
+
+impl InnovationRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of innovation numbers handed out so far.
+    pub fn num_innovations(&self) -> usize {
+        self.num_innovations
+    }
+
+    /// A fresh innovation number for a node, e.g. for the inputs and outputs of the first genome.
+    pub fn node_innovation_new(&mut self) -> usize {
+        self.innovation_next()
+    }
+
+    /// Innovation number of the connection between the nodes with these innovation numbers.
+    pub fn connection_innovation(
+        &mut self,
+        first_node_innovation: usize,
+        second_node_innovation: usize,
+    ) -> usize {
+        if let Some(&innovation) = self
+            .connections
+            .get(&(first_node_innovation, second_node_innovation))
+        {
+            return innovation;
+        }
+
+        let innovation = self.innovation_next();
+        self.connections
+            .insert((first_node_innovation, second_node_innovation), innovation);
+        innovation
+    }
+
+    /// Innovation number of the node created by splitting the connection with this innovation
+    /// number.
+    pub fn split_innovation(&mut self, connection_innovation: usize) -> usize {
+        self.split_innovation_unused(connection_innovation, |_| false)
+    }
+
+    /// Like `split_innovation`, for a genome that already uses some of the numbers of this split,
+    /// e.g. because it split the same connection before. Returns the first number handed out for
+    /// this split that `is_used` rejects, and records a new one when all of them are taken. The
+    /// same repeated split then gets the same number in every genome.
+    pub fn split_innovation_unused<IsUsedFn>(
+        &mut self,
+        connection_innovation: usize,
+        is_used: IsUsedFn,
+    ) -> usize
+    where
+        IsUsedFn: Fn(usize) -> bool,
+    {
+        if let Some(&innovation) = self
+            .splits
+            .get(&connection_innovation)
+            .and_then(|innovations| innovations.iter().find(|&&innovation| !is_used(innovation)))
+        {
+            return innovation;
+        }
+
+        let innovation = self.innovation_next();
+        self.splits
+            .entry(connection_innovation)
+            .or_default()
+            .push(innovation);
+        innovation
+    }
+
+    fn innovation_next(&mut self) -> usize {
+        self.num_innovations += 1;
+        self.num_innovations - 1
+    }
+}
+
+/// Innovation numbers of the nodes and connections of one graph, kept up to date by the graph
+/// once any of them is set. Deleting a node or removing a connection drops its number.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct InnovationIds {
+    pub(crate) nodes: Vec<Option<usize>>,
+    pub(crate) connections: HashMap<(usize, usize), usize>,
+    // INFO: reverse of `nodes`, for finding the node with an innovation number.
+    pub(crate) node_ids: HashMap<usize, usize>,
+}
+
+impl InnovationIds {
+    pub(crate) fn node_deleted(&mut self, node_id: usize) {
+        if let Some(innovation) = self.nodes.get_mut(node_id).and_then(Option::take) {
+            self.node_id_forget(innovation, node_id);
+        }
+        self.connections
+            .retain(|&(first_node_id, second_node_id), _| {
+                first_node_id != node_id && second_node_id != node_id
+            });
+    }
+
+    pub(crate) fn connection_removed(&mut self, first_node_id: usize, second_node_id: usize) {
+        self.connections.remove(&(first_node_id, second_node_id));
+    }
+
+    pub(crate) fn node_set(&mut self, node_id: usize, innovation: usize) {
+        if node_id >= self.nodes.len() {
+            self.nodes.resize(node_id + 1, None);
+        }
+
+        if let Some(old_innovation) = self.nodes[node_id].replace(innovation) {
+            self.node_id_forget(old_innovation, node_id);
+        }
+        self.node_ids.insert(innovation, node_id);
+    }
+
+    fn node_id_forget(&mut self, innovation: usize, node_id: usize) {
+        if self.node_ids.get(&innovation) == Some(&node_id) {
+            self.node_ids.remove(&innovation);
+        }
+    }
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Innovation number of a node, if it has one.
+    pub fn node_innovation(&self, node_id: usize) -> Option<usize> {
+        self.innovation_ids
+            .as_ref()?
+            .nodes
+            .get(node_id)
+            .copied()
+            .flatten()
+    }
+
+    /// Innovation number of the connection from `first_node_id` to `second_node_id`, if it has
+    /// one.
+    pub fn connection_innovation(
+        &self,
+        first_node_id: usize,
+        second_node_id: usize,
+    ) -> Option<usize> {
+        self.innovation_ids
+            .as_ref()?
+            .connections
+            .get(&(first_node_id, second_node_id))
+            .copied()
+    }
+
+    /// Give an existing node an innovation number, e.g. from
+    /// `InnovationRegistry::node_innovation_new`. Graphs of one population should use the same
+    /// numbers for the nodes they start with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::neural::innovation::InnovationRegistry;
+    /// use velox_graph::Graph;
+    ///
+    /// let mut registry = InnovationRegistry::new();
+    /// let input = registry.node_innovation_new();
+    /// let output = registry.node_innovation_new();
+    ///
+    /// // INFO: Initialize the graph and tag its nodes and connection.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    /// let input_id = graph.node_create(0);
+    /// let output_id = graph.node_create(1);
+    /// graph.node_innovation_set(input_id, input).unwrap();
+    /// graph.node_innovation_set(output_id, output).unwrap();
+    ///
+    /// graph.nodes_connection_set(input_id, output_id, 0.5).unwrap();
+    /// let connection = graph
+    ///     .connection_innovation_set(&mut registry, input_id, output_id)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(graph.node_innovation(output_id), Some(output));
+    /// assert_eq!(graph.connection_innovation(input_id, output_id), Some(connection));
+    /// ```
+    pub fn node_innovation_set(
+        &mut self,
+        node_id: usize,
+        innovation: usize,
+    ) -> Result<(), VeloxGraphError> {
+        self.node_ref(node_id)?;
+
+        self.innovation_ids
+            .get_or_insert_with(InnovationIds::default)
+            .node_set(node_id, innovation);
+
+        Ok(())
+    }
+
+    /// Give an existing connection the innovation number the registry has for its two nodes,
+    /// and return it. Both nodes need an innovation number.
+    pub fn connection_innovation_set(
+        &mut self,
+        registry: &mut InnovationRegistry,
+        first_node_id: usize,
+        second_node_id: usize,
+    ) -> Result<usize, VeloxGraphError> {
+        if !self.has_connection(first_node_id, second_node_id) {
+            self.node_ref(first_node_id)?;
+            self.node_ref(second_node_id)?;
+            return Err(VeloxGraphError::ConnectionNotSet(second_node_id));
+        }
+
+        let (Some(first_node_innovation), Some(second_node_innovation)) = (
+            self.node_innovation(first_node_id),
+            self.node_innovation(second_node_id),
+        ) else {
+            return Err(VeloxGraphError::InvalidArgument(format!(
+                "connection {} -> {} joins a node without an innovation number",
+                first_node_id, second_node_id
+            )));
+        };
+
+        let innovation =
+            registry.connection_innovation(first_node_innovation, second_node_innovation);
+        self.innovation_ids
+            .get_or_insert_with(InnovationIds::default)
+            .connections
+            .insert((first_node_id, second_node_id), innovation);
+
+        Ok(innovation)
+    }
+
+    /// Id of the node with this innovation number, if the graph has one.
+    pub(crate) fn node_id_with_innovation(&self, innovation: usize) -> Option<usize> {
+        self.innovation_ids
+            .as_ref()?
+            .node_ids
+            .get(&innovation)
+            .copied()
+    }
+}
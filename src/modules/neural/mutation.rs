@@ -0,0 +1,307 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::graph_trait::Graph;
+use crate::modules::neural::innovation::InnovationRegistry;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Random pairs `mutate_add_connection` tries before checking every pair.
+const ADD_CONNECTION_MAX_SAMPLES: usize = 32;
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Connect a random pair of nodes that are not connected yet, and return the pair.
+    ///
+    /// Only nodes with an innovation number take part, and the new connection gets its number
+    /// from `registry`. Every candidate pair is equally likely. With `allow_cycles` false, pairs
+    /// that would close a cycle are skipped, self connections included. Returns `None` when no
+    /// pair is left.
+    ///
+    /// Pairs are sampled at random and checked one at a time, so a call usually costs a few
+    /// reachability searches. Only when most pairs are taken does it fall back to checking every
+    /// pair, which costs one ancestor search per node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::neural::innovation::InnovationRegistry;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph with two tagged nodes.
+    /// let mut registry = InnovationRegistry::new();
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    /// for index in 0..2 {
+    ///     let node_id = graph.node_create(index);
+    ///     graph
+    ///         .node_innovation_set(node_id, registry.node_innovation_new())
+    ///         .unwrap();
+    /// }
+    ///
+    /// // INFO: Without cycles, only one connection fits between two nodes.
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let (first_node_id, second_node_id) = graph
+    ///     .mutate_add_connection(&mut registry, &mut rng, 1.0, false)
+    ///     .unwrap()
+    ///     .unwrap();
+    ///
+    /// assert!(graph.connection_innovation(first_node_id, second_node_id).is_some());
+    /// assert_eq!(
+    ///     graph
+    ///         .mutate_add_connection(&mut registry, &mut rng, 1.0, false)
+    ///         .unwrap(),
+    ///     None
+    /// );
+    /// ```
+    pub fn mutate_add_connection<RngT>(
+        &mut self,
+        registry: &mut InnovationRegistry,
+        rng: &mut RngT,
+        connection_data: ConnectionDataT,
+        allow_cycles: bool,
+    ) -> Result<Option<(usize, usize)>, VeloxGraphError>
+    where
+        RngT: Rng,
+    {
+        let node_ids: Vec<usize> = self
+            .node_ids()
+            .filter(|&node_id| self.node_innovation(node_id).is_some())
+            .collect();
+        if node_ids.is_empty() {
+            return Ok(None);
+        }
+
+        // INFO: rejection sampling keeps every free pair equally likely. Only a graph with few
+        // free pairs left falls through to the full scan.
+        let mut chosen = None;
+        for _ in 0..ADD_CONNECTION_MAX_SAMPLES {
+            let first_node_id = node_ids[rng.random_range(0..node_ids.len())];
+            let second_node_id = node_ids[rng.random_range(0..node_ids.len())];
+            if self.add_connection_allowed(first_node_id, second_node_id, allow_cycles)? {
+                chosen = Some((first_node_id, second_node_id));
+                break;
+            }
+        }
+        if chosen.is_none() {
+            chosen = self.add_connection_scan(&node_ids, rng, allow_cycles)?;
+        }
+        let Some((first_node_id, second_node_id)) = chosen else {
+            return Ok(None);
+        };
+
+        self.nodes_connection_set(first_node_id, second_node_id, connection_data)?;
+        self.connection_innovation_set(registry, first_node_id, second_node_id)?;
+
+        Ok(Some((first_node_id, second_node_id)))
+    }
+
+    /// Check if `mutate_add_connection` may connect `first_node_id` to `second_node_id`.
+    fn add_connection_allowed(
+        &self,
+        first_node_id: usize,
+        second_node_id: usize,
+        allow_cycles: bool,
+    ) -> Result<bool, VeloxGraphError> {
+        if self.has_connection(first_node_id, second_node_id) {
+            return Ok(false);
+        }
+
+        // INFO: first -> second closes a cycle when second already reaches first.
+        Ok(allow_cycles || !self.is_reachable(second_node_id, first_node_id)?)
+    }
+
+    /// Pick a uniformly random allowed pair by looking at every pair, keeping one candidate at a
+    /// time (reservoir sampling) so memory stays linear.
+    fn add_connection_scan<RngT>(
+        &self,
+        node_ids: &[usize],
+        rng: &mut RngT,
+        allow_cycles: bool,
+    ) -> Result<Option<(usize, usize)>, VeloxGraphError>
+    where
+        RngT: Rng,
+    {
+        let mut chosen = None;
+        let mut num_candidates = 0;
+        let mut closes_cycle = vec![false; self.nodes_vector.len()];
+        for &first_node_id in node_ids {
+            if !allow_cycles {
+                closes_cycle.fill(false);
+                closes_cycle[first_node_id] = true;
+                for ancestor_id in self.ancestors(first_node_id, None)? {
+                    closes_cycle[ancestor_id] = true;
+                }
+            }
+
+            for &second_node_id in node_ids {
+                if closes_cycle[second_node_id]
+                    || self.has_connection(first_node_id, second_node_id)
+                {
+                    continue;
+                }
+
+                num_candidates += 1;
+                if rng.random_range(0..num_candidates) == 0 {
+                    chosen = Some((first_node_id, second_node_id));
+                }
+            }
+        }
+
+        Ok(chosen)
+    }
+
+    /// Replace a random connection with a new node and two connections through it, and return
+    /// the id of the new node.
+    ///
+    /// Only connections between nodes with an innovation number take part. `split_fn` gets the
+    /// data of the removed connection and returns the data for the connections into and out of
+    /// the new node. The new node gets the first number `registry` has for splitting that
+    /// connection that this graph does not use yet (see
+    /// `InnovationRegistry::split_innovation_unused`). Returns `None` when no connection can be
+    /// split.
+    ///
+    /// With degree limits enabled, fails with `DegreeLimitReached` and leaves the graph unchanged
+    /// when the policy cannot fit both new connections. Eviction policies may remove other
+    /// connections of the two end nodes to make room.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::neural::innovation::InnovationRegistry;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph with one tagged connection.
+    /// let mut registry = InnovationRegistry::new();
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    /// for index in 0..2 {
+    ///     let node_id = graph.node_create(index);
+    ///     graph
+    ///         .node_innovation_set(node_id, registry.node_innovation_new())
+    ///         .unwrap();
+    /// }
+    /// graph.nodes_connection_set(0, 1, 0.5).unwrap();
+    ///
+    /// // INFO: Like NEAT, the incoming connection gets weight 1 and the outgoing one keeps the old
+    /// // weight.
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let new_node_id = graph
+    ///     .mutate_split_connection(&mut registry, &mut rng, 2, |weight| (1.0, weight))
+    ///     .unwrap()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(new_node_id, 2);
+    /// assert!(graph.connection_innovation(0, 2).is_some());
+    /// assert!(graph.connection_innovation(2, 1).is_some());
+    /// assert_eq!(graph.connection_innovation(0, 1), None);
+    /// ```
+    pub fn mutate_split_connection<RngT, SplitFn>(
+        &mut self,
+        registry: &mut InnovationRegistry,
+        rng: &mut RngT,
+        node_data: NodeDataT,
+        split_fn: SplitFn,
+    ) -> Result<Option<usize>, VeloxGraphError>
+    where
+        RngT: Rng,
+        SplitFn: FnOnce(ConnectionDataT) -> (ConnectionDataT, ConnectionDataT),
+    {
+        let candidates: Vec<(usize, usize)> = self
+            .connection_pairs()
+            .filter(|&(first_node_id, second_node_id)| {
+                self.node_innovation(first_node_id).is_some()
+                    && self.node_innovation(second_node_id).is_some()
+            })
+            .collect();
+
+        let Some(&(first_node_id, second_node_id)) = candidates.choose(rng) else {
+            return Ok(None);
+        };
+        // INFO: with degree limits, make sure both new connections fit before changing anything.
+        self.degree_limits_split_check(first_node_id, second_node_id)?;
+
+        let connection_innovation = match self.connection_innovation(first_node_id, second_node_id)
+        {
+            Some(innovation) => innovation,
+            None => self.connection_innovation_set(registry, first_node_id, second_node_id)?,
+        };
+        let Some(connection_data) = self.connection_data(first_node_id, second_node_id).cloned()
+        else {
+            return Err(VeloxGraphError::ConnectionNotSet(second_node_id));
+        };
+        self.nodes_connection_remove(first_node_id, second_node_id)?;
+
+        // INFO: splitting the same connection twice must not give two nodes the same number.
+        let node_innovation = registry
+            .split_innovation_unused(connection_innovation, |innovation| {
+                self.node_id_with_innovation(innovation).is_some()
+            });
+
+        let new_node_id = self.node_create(node_data);
+        self.node_innovation_set(new_node_id, node_innovation)?;
+
+        let (connection_data_in, connection_data_out) = split_fn(connection_data);
+        self.nodes_connection_set(first_node_id, new_node_id, connection_data_in)?;
+        self.connection_innovation_set(registry, first_node_id, new_node_id)?;
+        self.nodes_connection_set(new_node_id, second_node_id, connection_data_out)?;
+        self.connection_innovation_set(registry, new_node_id, second_node_id)?;
+
+        Ok(Some(new_node_id))
+    }
+
+    /// Remove a random connection and return its pair of nodes, or `None` if there are no
+    /// connections.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    /// for index in 0..2 {
+    ///     graph.node_create(index);
+    /// }
+    /// graph.nodes_connection_set(0, 1, 0.5).unwrap();
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// assert_eq!(graph.mutate_remove_connection(&mut rng).unwrap(), Some((0, 1)));
+    /// assert_eq!(graph.mutate_remove_connection(&mut rng).unwrap(), None);
+    /// ```
+    pub fn mutate_remove_connection<RngT>(
+        &mut self,
+        rng: &mut RngT,
+    ) -> Result<Option<(usize, usize)>, VeloxGraphError>
+    where
+        RngT: Rng,
+    {
+        let candidates: Vec<(usize, usize)> = self.connection_pairs().collect();
+
+        let Some(&(first_node_id, second_node_id)) = candidates.choose(rng) else {
+            return Ok(None);
+        };
+        self.nodes_connection_remove(first_node_id, second_node_id)?;
+
+        Ok(Some((first_node_id, second_node_id)))
+    }
+}
@@ -0,0 +1,137 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::neural::aggregation::NeighborDirection;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// How the weights of a node's connections are rescaled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Normalization {
+    /// Divide by the sum of absolute weights.
+    L1,
+    /// Divide by the square root of the sum of squared weights.
+    L2,
+    /// Replace by `exp(weight)` divided by the sum over the node's connections, so the weights
+    /// are positive and sum to 1.
+    Softmax,
+    /// Divide by the largest absolute weight.
+    Max,
+}
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Normalize the connection weights of every node, over its outgoing or incoming
+    /// connections.
+    ///
+    /// `get_fn` reads the weight from the connection data and `set_fn` writes the normalized one
+    /// back. Each pass walks every connection once, with no lookups per connection. Nodes whose
+    /// weights are all 0 are left unchanged, except with `Softmax`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::neural::aggregation::NeighborDirection;
+    /// use velox_graph::neural::normalization::Normalization;
+    /// use velox_graph::ConnectionsForward;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph. node0 connects to node1 and node2.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    /// for index in 0..3 {
+    ///     graph.node_create(index);
+    /// }
+    /// graph.nodes_connection_set(0, 1, 3.0).unwrap();
+    /// graph.nodes_connection_set(0, 2, -1.0).unwrap();
+    ///
+    /// graph.normalize_weights(
+    ///     Normalization::L1,
+    ///     NeighborDirection::Outgoing,
+    ///     |weight| *weight,
+    ///     |weight, normalized| *weight = normalized,
+    /// );
+    ///
+    /// let connections = graph.node_get(0).unwrap().connections_forward();
+    /// assert_eq!(connections.get(1).unwrap().data, 0.75);
+    /// assert_eq!(connections.get(2).unwrap().data, -0.25);
+    /// ```
+    pub fn normalize_weights<GetFn, SetFn>(
+        &mut self,
+        normalization: Normalization,
+        direction: NeighborDirection,
+        get_fn: GetFn,
+        mut set_fn: SetFn,
+    ) where
+        GetFn: Fn(&ConnectionDataT) -> f64,
+        SetFn: FnMut(&mut ConnectionDataT, f64),
+    {
+        // INFO: the node whose connections a connection is normalized with.
+        let owner = |first_node_id: usize, second_node_id: usize| match direction {
+            NeighborDirection::Outgoing => first_node_id,
+            NeighborDirection::Incoming => second_node_id,
+        };
+
+        let num_slots = self.nodes_vector.len();
+        let mut totals = vec![0.0; num_slots];
+        let mut max_weights = vec![f64::NEG_INFINITY; num_slots];
+
+        // INFO: softmax subtracts the largest weight so the exponentials cannot overflow.
+        if normalization == Normalization::Softmax {
+            for (first_node_id, node) in self.nodes() {
+                for connection in node.connections_forward.data() {
+                    let owner_id = owner(first_node_id, connection.node_id());
+                    max_weights[owner_id] = max_weights[owner_id].max(get_fn(&connection.data));
+                }
+            }
+        }
+
+        for (first_node_id, node) in self.nodes() {
+            for connection in node.connections_forward.data() {
+                let owner_id = owner(first_node_id, connection.node_id());
+                let weight = get_fn(&connection.data);
+                let total = &mut totals[owner_id];
+                match normalization {
+                    Normalization::L1 => *total += weight.abs(),
+                    Normalization::L2 => *total += weight * weight,
+                    Normalization::Softmax => *total += (weight - max_weights[owner_id]).exp(),
+                    Normalization::Max => *total = total.max(weight.abs()),
+                }
+            }
+        }
+
+        if normalization == Normalization::L2 {
+            totals.iter_mut().for_each(|total| *total = total.sqrt());
+        }
+
+        for (first_node_id, node_option) in self.nodes_vector.iter_mut().enumerate() {
+            let Some(node) = node_option else {
+                continue;
+            };
+
+            for (second_node_id, connection_data) in node.connections_forward.connections_mut() {
+                let owner_id = owner(first_node_id, second_node_id);
+                let total = totals[owner_id];
+                if total == 0.0 {
+                    continue;
+                }
+
+                let weight = get_fn(connection_data);
+                let normalized = match normalization {
+                    Normalization::Softmax => (weight - max_weights[owner_id]).exp() / total,
+                    _ => weight / total,
+                };
+                set_fn(connection_data, normalized);
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+#[allow(private_bounds)]
+impl<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+    VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    /// Propagate activations one step along the forward connections, pushing from active nodes.
+    ///
+    /// `input_activations` is indexed by node id. Missing entries count as 0. Every node with a
+    /// non-zero activation adds `activation * weight_fn(connection)` to each node it connects to,
+    /// so the cost follows the number of active connections. The result is indexed by node id,
+    /// with `activation_fn` applied to the sum of every used slot and 0 for empty slots.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphVec;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    ///
+    /// // INFO: Inputs 0 and 1 both feed node2.
+    /// for index in 0..3 {
+    ///     graph.node_create(index);
+    /// }
+    /// graph.nodes_connection_set(0, 2, 0.5).unwrap();
+    /// graph.nodes_connection_set(1, 2, -2.0).unwrap();
+    ///
+    /// // INFO: Only node0 is active.
+    /// let relu = |sum: f64| sum.max(0.0);
+    /// let output = graph.propagate(&[4.0, 0.0], |weight| *weight, relu).unwrap();
+    ///
+    /// assert_eq!(output, vec![0.0, 0.0, 2.0]);
+    /// ```
+    pub fn propagate<WeightFn, ActivationFn>(
+        &self,
+        input_activations: &[f64],
+        weight_fn: WeightFn,
+        activation_fn: ActivationFn,
+    ) -> Result<Vec<f64>, VeloxGraphError>
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+        ActivationFn: Fn(f64) -> f64,
+    {
+        self.activations_check(input_activations)?;
+        let mut sums = vec![0.0; self.nodes_vector.len()];
+        self.inputs_pushed(input_activations, weight_fn, &mut sums);
+
+        Ok(self.activations_applied(sums, activation_fn))
+    }
+
+    /// Propagate activations one step, pulling into every node through `connections_backward`.
+    ///
+    /// Gives the same result as `propagate`, but visits every connection instead of only the
+    /// active ones. This suits dense activity. Each connection's weight is looked up from the
+    /// source node, which is constant time with `HashConnectionsForward` and a linear scan of the
+    /// source's connections with `VecConnectionsForward`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use velox_graph::graph::VeloxGraphHash;
+    /// use velox_graph::Graph;
+    ///
+    /// // INFO: Initialize the graph.
+    /// let mut graph: VeloxGraphHash<usize, u32, f64> = VeloxGraphHash::new();
+    ///
+    /// // INFO: Inputs 0 and 1 both feed node2.
+    /// for index in 0..3 {
+    ///     graph.node_create(index);
+    /// }
+    /// graph.nodes_connection_set(0, 2, 0.5).unwrap();
+    /// graph.nodes_connection_set(1, 2, -2.0).unwrap();
+    ///
+    /// let output = graph
+    ///     .propagate_pull(&[4.0, 0.5, 0.0], |weight| *weight, |sum| sum)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(output, vec![0.0, 0.0, 1.0]);
+    /// ```
+    pub fn propagate_pull<WeightFn, ActivationFn>(
+        &self,
+        input_activations: &[f64],
+        weight_fn: WeightFn,
+        activation_fn: ActivationFn,
+    ) -> Result<Vec<f64>, VeloxGraphError>
+    where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+        ActivationFn: Fn(f64) -> f64,
+    {
+        self.activations_check(input_activations)?;
+        let mut sums = vec![0.0; self.nodes_vector.len()];
+        self.inputs_pulled(input_activations, weight_fn, &mut sums);
+
+        Ok(self.activations_applied(sums, activation_fn))
+    }
+
+    /// Add `activation * weight_fn(connection)` to `sums` for every forward connection of a node
+    /// with a non-zero activation. `sums` must have one entry per node slot.
+    pub(crate) fn inputs_pushed<WeightFn>(
+        &self,
+        activations: &[f64],
+        weight_fn: WeightFn,
+        sums: &mut [f64],
+    ) where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        for (node_id, &activation) in activations.iter().enumerate() {
+            if activation == 0.0 {
+                continue;
+            }
+
+            let Some(Some(node)) = self.nodes_vector.get(node_id) else {
+                continue;
+            };
+            for connection in node.connections_forward.data() {
+                sums[connection.node_id()] += activation * weight_fn(&connection.data);
+            }
+        }
+    }
+
+    /// Same sums as `inputs_pushed`, gathered per node through `connections_backward`.
+    pub(crate) fn inputs_pulled<WeightFn>(
+        &self,
+        activations: &[f64],
+        weight_fn: WeightFn,
+        sums: &mut [f64],
+    ) where
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+    {
+        for (node_id, node) in self.nodes() {
+            for connection in node.connections_backward.data() {
+                let source_node_id = connection.node_id();
+                let activation = activations.get(source_node_id).copied().unwrap_or(0.0);
+                if activation == 0.0 {
+                    continue;
+                }
+
+                if let Some(connection_data) = self.connection_data(source_node_id, node_id) {
+                    sums[node_id] += activation * weight_fn(connection_data);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn activations_check(&self, activations: &[f64]) -> Result<(), VeloxGraphError> {
+        if activations.len() > self.nodes_vector.len() {
+            return Err(VeloxGraphError::InvalidArgument(format!(
+                "{} activations given for {} node slots",
+                activations.len(),
+                self.nodes_vector.len()
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn activations_applied<ActivationFn>(
+        &self,
+        mut sums: Vec<f64>,
+        activation_fn: ActivationFn,
+    ) -> Vec<f64>
+    where
+        ActivationFn: Fn(f64) -> f64,
+    {
+        for (node_id, node_option) in self.nodes_vector.iter().enumerate() {
+            sums[node_id] = match node_option {
+                Some(_) => activation_fn(sums[node_id]),
+                None => 0.0,
+            };
+        }
+
+        sums
+    }
+}
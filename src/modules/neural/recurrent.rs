@@ -0,0 +1,197 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Which connections a `RecurrentEngine` step walks to gather the input of each node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepDirection {
+    /// Every node sums its sources through `connections_backward`. Suits dense activity. Each
+    /// weight lookup scans the source's connections with `VecConnectionsForward`, see
+    /// `VeloxGraph::propagate_pull`.
+    Pull,
+    /// Every node with a non-zero state pushes it through `connections_forward`. Suits sparse
+    /// activity.
+    Push,
+}
+
+/// Time stepped execution of a recurrent network with one state value per node.
+///
+/// Each step computes the next state of every node from the previous state only, then swaps
+/// the two buffers, so loops in the graph give the same result whatever order nodes are visited
+/// in. Empty slots always have state 0.
+///
+/// # Example
+///
+/// ```
+/// use velox_graph::graph::VeloxGraphVec;
+/// use velox_graph::neural::recurrent::{RecurrentEngine, StepDirection};
+/// use velox_graph::Graph;
+///
+/// // INFO: Initialize the graph. Two nodes that copy each other.
+/// let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+/// for index in 0..2 {
+///     graph.node_create(index);
+/// }
+/// graph.nodes_connection_set(0, 1, 1.0).unwrap();
+/// graph.nodes_connection_set(1, 0, 1.0).unwrap();
+///
+/// // INFO: The state swaps back and forth every step.
+/// let mut engine = RecurrentEngine::new(vec![1.0, 0.0], StepDirection::Pull);
+/// engine.trace_nodes(&[0]);
+/// engine.run(&mut graph, 3, |weight| *weight, |_, _, _, input| input);
+///
+/// assert_eq!(engine.state(), &[0.0, 1.0]);
+/// assert_eq!(engine.traces()[0], vec![0.0, 1.0, 0.0]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RecurrentEngine {
+    direction: StepDirection,
+    current: Vec<f64>,
+    next: Vec<f64>,
+    inputs: Vec<f64>,
+    num_steps: usize,
+    traced_node_ids: Vec<usize>,
+    traces: Vec<Vec<f64>>,
+}
+
+impl RecurrentEngine {
+    /// Start from `initial_state`, indexed by node id. Missing entries start at 0, and entries
+    /// past the last node slot are dropped.
+    pub fn new(initial_state: Vec<f64>, direction: StepDirection) -> Self {
+        Self {
+            direction,
+            current: initial_state,
+            next: Vec::new(),
+            inputs: Vec::new(),
+            num_steps: 0,
+            traced_node_ids: Vec::new(),
+            traces: Vec::new(),
+        }
+    }
+
+    /// State of every node after the last step, indexed by node id.
+    pub fn state(&self) -> &[f64] {
+        &self.current
+    }
+
+    /// Overwrite the state of one node, e.g. to clamp an input between steps.
+    pub fn state_set(&mut self, node_id: usize, value: f64) {
+        if node_id >= self.current.len() {
+            self.current.resize(node_id + 1, 0.0);
+        }
+
+        self.current[node_id] = value;
+    }
+
+    /// Number of steps run so far.
+    pub fn num_steps(&self) -> usize {
+        self.num_steps
+    }
+
+    /// Record the state of these nodes after every following step. Replaces any earlier
+    /// selection and clears the recorded traces.
+    pub fn trace_nodes(&mut self, node_ids: &[usize]) {
+        self.traced_node_ids = node_ids.to_vec();
+        self.traces = vec![Vec::new(); node_ids.len()];
+    }
+
+    /// Recorded traces, one per traced node in the order given to `trace_nodes`, with one value
+    /// per step.
+    pub fn traces(&self) -> &[Vec<f64>] {
+        &self.traces
+    }
+
+    /// Run `num_steps` steps. See `step`.
+    #[allow(private_bounds)]
+    pub fn run<
+        NodeIdT,
+        ConnForwardT,
+        ConnBackwardT,
+        NodeDataT,
+        ConnectionDataT,
+        WeightFn,
+        UpdateFn,
+    >(
+        &mut self,
+        graph: &mut VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>,
+        num_steps: usize,
+        weight_fn: WeightFn,
+        mut update_fn: UpdateFn,
+    ) where
+        ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+        ConnBackwardT: ConnectionsBackward<NodeIdT>,
+        NodeIdT: UnsignedInt,
+        NodeDataT: Clone + Serialize + DeserializeOwned,
+        ConnectionDataT: Clone + Serialize + DeserializeOwned,
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+        UpdateFn: FnMut(usize, &mut NodeDataT, f64, f64) -> f64,
+    {
+        for _ in 0..num_steps {
+            self.step(graph, &weight_fn, &mut update_fn);
+        }
+    }
+
+    /// Advance one step.
+    ///
+    /// The input of each node is the sum of `weight_fn(connection) * previous_state` over its
+    /// incoming connections. The next state is `update_fn(node_id, node_data, previous_state,
+    /// input)`, which may also update the node data.
+    #[allow(private_bounds)]
+    pub fn step<
+        NodeIdT,
+        ConnForwardT,
+        ConnBackwardT,
+        NodeDataT,
+        ConnectionDataT,
+        WeightFn,
+        UpdateFn,
+    >(
+        &mut self,
+        graph: &mut VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>,
+        weight_fn: WeightFn,
+        mut update_fn: UpdateFn,
+    ) where
+        ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+        ConnBackwardT: ConnectionsBackward<NodeIdT>,
+        NodeIdT: UnsignedInt,
+        NodeDataT: Clone + Serialize + DeserializeOwned,
+        ConnectionDataT: Clone + Serialize + DeserializeOwned,
+        WeightFn: Fn(&ConnectionDataT) -> f64,
+        UpdateFn: FnMut(usize, &mut NodeDataT, f64, f64) -> f64,
+    {
+        // INFO: follow the graph if nodes were created or deleted since the last step.
+        let num_slots = graph.nodes_vector.len();
+        self.current.resize(num_slots, 0.0);
+        self.next.resize(num_slots, 0.0);
+        self.inputs.clear();
+        self.inputs.resize(num_slots, 0.0);
+
+        // INFO: gather inputs from the previous state only.
+        match self.direction {
+            StepDirection::Pull => graph.inputs_pulled(&self.current, weight_fn, &mut self.inputs),
+            StepDirection::Push => graph.inputs_pushed(&self.current, weight_fn, &mut self.inputs),
+        }
+
+        for (node_id, node_option) in graph.nodes_vector.iter_mut().enumerate() {
+            self.next[node_id] = match node_option {
+                Some(node) => update_fn(
+                    node_id,
+                    &mut node.data,
+                    self.current[node_id],
+                    self.inputs[node_id],
+                ),
+                None => 0.0,
+            };
+        }
+
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.num_steps += 1;
+
+        for (trace, &node_id) in self.traces.iter_mut().zip(&self.traced_node_ids) {
+            trace.push(self.current.get(node_id).copied().unwrap_or(0.0));
+        }
+    }
+}
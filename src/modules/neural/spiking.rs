@@ -0,0 +1,366 @@
+use crate::modules::connections_backward::connections_backward_trait::ConnectionsBackward;
+use crate::modules::connections_forward::connections_forward_trait::ConnectionsForward;
+use crate::modules::error::VeloxGraphError;
+use crate::modules::graph::VeloxGraph;
+use crate::modules::node::Node;
+use crate::modules::unsigned_int::UnsignedInt;
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// User callbacks that drive a `SpikeSimulator`.
+pub trait SpikeHandler<NodeDataT, ConnectionDataT> {
+    /// Time a spike takes to travel along a connection. Must be positive, so a loop of
+    /// connections cannot fire forever at a single point in time.
+    fn delay(&mut self, connection_data: &ConnectionDataT) -> f64;
+
+    /// A spike arrives at `node_id` over a connection. Update the node state (membrane potential)
+    /// and return true if the node fires.
+    fn spike_arrived(
+        &mut self,
+        time: f64,
+        node_id: usize,
+        node_data: &mut NodeDataT,
+        connection_data: &ConnectionDataT,
+    ) -> bool;
+
+    /// Plasticity hook, called with the spike times at both ends of a connection whenever one
+    /// of them fires and the other has fired before. Does nothing by default.
+    ///
+    /// `pre_spike_time <= post_spike_time` means the source fired first (potentiation in STDP).
+    #[allow(unused_variables)]
+    fn plasticity(
+        &mut self,
+        connection_data: &mut ConnectionDataT,
+        pre_spike_time: f64,
+        post_spike_time: f64,
+    ) {
+    }
+}
+
+/// One node firing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spike {
+    pub time: f64,
+    pub node_id: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct SpikeEvent {
+    time: f64,
+    // INFO: breaks time ties in scheduling order, so runs are deterministic.
+    sequence: u64,
+    node_id: usize,
+    // INFO: None for an external stimulus, which fires the node directly.
+    source_node_id: Option<usize>,
+}
+
+impl Eq for SpikeEvent {}
+
+impl Ord for SpikeEvent {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .time
+            .total_cmp(&self.time)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl PartialOrd for SpikeEvent {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Event driven spiking simulation over a `VeloxGraph`.
+///
+/// Spikes travel along forward connections with a delay from `SpikeHandler::delay`, and are
+/// delivered in time order. Connections are looked up when a spike arrives, so changes made by
+/// plasticity or by the user between runs take effect for spikes already in flight. Spikes over
+/// a connection removed in the meantime are dropped.
+///
+/// # Example
+///
+/// ```
+/// use velox_graph::graph::VeloxGraphVec;
+/// use velox_graph::neural::spiking::{Spike, SpikeHandler, SpikeSimulator};
+/// use velox_graph::Graph;
+///
+/// // INFO: Connection data is (weight, delay). Node data is the membrane potential.
+/// struct Integrate;
+///
+/// impl SpikeHandler<f64, (f64, f64)> for Integrate {
+///     fn delay(&mut self, connection_data: &(f64, f64)) -> f64 {
+///         connection_data.1
+///     }
+///
+///     fn spike_arrived(
+///         &mut self,
+///         _time: f64,
+///         _node_id: usize,
+///         potential: &mut f64,
+///         connection_data: &(f64, f64),
+///     ) -> bool {
+///         *potential += connection_data.0;
+///         if *potential >= 1.0 {
+///             *potential = 0.0;
+///             return true;
+///         }
+///         false
+///     }
+/// }
+///
+/// // INFO: Initialize the graph. node0 drives node1 with weight 0.6 and delay 2.
+/// let mut graph: VeloxGraphVec<usize, f64, (f64, f64)> = VeloxGraphVec::new();
+/// for _ in 0..2 {
+///     graph.node_create(0.0);
+/// }
+/// graph.nodes_connection_set(0, 1, (0.6, 2.0)).unwrap();
+///
+/// // INFO: node0 fires twice. Only the second arrival pushes node1 over the threshold.
+/// let mut simulator = SpikeSimulator::new();
+/// simulator.stimulate(0, 0.0).unwrap();
+/// simulator.stimulate(0, 1.0).unwrap();
+/// let spikes = simulator.run_until(&mut graph, 10.0, &mut Integrate).unwrap();
+///
+/// assert_eq!(spikes, vec![
+///     Spike { time: 0.0, node_id: 0 },
+///     Spike { time: 1.0, node_id: 0 },
+///     Spike { time: 3.0, node_id: 1 },
+/// ]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SpikeSimulator {
+    time: f64,
+    queue: BinaryHeap<SpikeEvent>,
+    next_sequence: u64,
+    last_spike_times: Vec<Option<f64>>,
+}
+
+impl SpikeSimulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current simulation time.
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Number of spikes scheduled but not delivered yet.
+    pub fn pending_events(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Time this node last fired, if it has fired.
+    pub fn last_spike_time(&self, node_id: usize) -> Option<f64> {
+        self.last_spike_times.get(node_id).copied().flatten()
+    }
+
+    /// Make a node fire at `time`, as an external input.
+    pub fn stimulate(&mut self, node_id: usize, time: f64) -> Result<(), VeloxGraphError> {
+        if time.is_nan() || time < self.time {
+            return Err(VeloxGraphError::InvalidArgument(format!(
+                "stimulus time {} is before the current simulation time {}",
+                time, self.time
+            )));
+        }
+
+        self.schedule(time, node_id, None);
+
+        Ok(())
+    }
+
+    /// Deliver every event up to and including `end_time`, and return the spikes in the order
+    /// they happened. The simulation time is `end_time` afterwards.
+    #[allow(private_bounds)]
+    pub fn run_until<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT, HandlerT>(
+        &mut self,
+        graph: &mut VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>,
+        end_time: f64,
+        handler: &mut HandlerT,
+    ) -> Result<Vec<Spike>, VeloxGraphError>
+    where
+        ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+        ConnBackwardT: ConnectionsBackward<NodeIdT>,
+        NodeIdT: UnsignedInt,
+        NodeDataT: Clone + Serialize + DeserializeOwned,
+        ConnectionDataT: Clone + Serialize + DeserializeOwned,
+        HandlerT: SpikeHandler<NodeDataT, ConnectionDataT>,
+    {
+        let mut spikes = Vec::new();
+
+        while let Some(&event) = self.queue.peek() {
+            if event.time > end_time {
+                break;
+            }
+            self.queue.pop();
+            self.time = event.time;
+
+            let fires = match event.source_node_id {
+                None => graph.node_ref(event.node_id).is_ok(),
+                Some(source_node_id) => {
+                    match connection_and_target(
+                        &mut graph.nodes_vector,
+                        source_node_id,
+                        event.node_id,
+                    ) {
+                        Some((connection_data, node_data)) => handler.spike_arrived(
+                            event.time,
+                            event.node_id,
+                            node_data,
+                            connection_data,
+                        ),
+                        None => false,
+                    }
+                }
+            };
+
+            if fires {
+                self.fire(graph, event.node_id, event.time, handler)?;
+                spikes.push(Spike {
+                    time: event.time,
+                    node_id: event.node_id,
+                });
+            }
+        }
+
+        self.time = self.time.max(end_time);
+
+        Ok(spikes)
+    }
+
+    fn schedule(&mut self, time: f64, node_id: usize, source_node_id: Option<usize>) {
+        self.queue.push(SpikeEvent {
+            time,
+            sequence: self.next_sequence,
+            node_id,
+            source_node_id,
+        });
+        self.next_sequence += 1;
+    }
+
+    /// Apply plasticity around a firing node, then send its spike along every forward
+    /// connection. Delays are read before plasticity runs.
+    fn fire<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT, HandlerT>(
+        &mut self,
+        graph: &mut VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>,
+        node_id: usize,
+        time: f64,
+        handler: &mut HandlerT,
+    ) -> Result<(), VeloxGraphError>
+    where
+        ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+        ConnBackwardT: ConnectionsBackward<NodeIdT>,
+        NodeIdT: UnsignedInt,
+        NodeDataT: Clone + Serialize + DeserializeOwned,
+        ConnectionDataT: Clone + Serialize + DeserializeOwned,
+        HandlerT: SpikeHandler<NodeDataT, ConnectionDataT>,
+    {
+        if node_id >= self.last_spike_times.len() {
+            self.last_spike_times.resize(graph.nodes_vector.len(), None);
+        }
+
+        let node = graph
+            .nodes_vector
+            .get(node_id)
+            .and_then(Option::as_ref)
+            .ok_or(VeloxGraphError::SlotNotUsed(node_id))?;
+
+        // INFO: check every delay first, so a bad one leaves the graph and the queue untouched.
+        let mut delays = Vec::with_capacity(node.connections_forward.data().len());
+        for connection in node.connections_forward.data() {
+            let delay = handler.delay(&connection.data);
+            if delay.is_nan() || delay <= 0.0 {
+                return Err(VeloxGraphError::InvalidArgument(format!(
+                    "spike delay {} from node {} to node {} is not positive",
+                    delay,
+                    node_id,
+                    connection.node_id()
+                )));
+            }
+            delays.push((connection.node_id(), delay));
+        }
+
+        // INFO: incoming connections pair this spike with the last spike of their source.
+        let source_node_ids: Vec<usize> = graph.backward_ids(node_id).collect();
+        for source_node_id in source_node_ids {
+            // INFO: a self connection is handled once, with the outgoing connections.
+            if source_node_id == node_id {
+                continue;
+            }
+            let Some(pre_spike_time) = self.last_spike_time(source_node_id) else {
+                continue;
+            };
+            let Some(Some(source_node)) = graph.nodes_vector.get_mut(source_node_id) else {
+                continue;
+            };
+            if let Ok(connection) = source_node.connections_forward.get(node_id) {
+                handler.plasticity(&mut connection.data, pre_spike_time, time);
+            }
+        }
+
+        // INFO: outgoing connections pair it with the last spike of their target.
+        if let Some(Some(node)) = graph.nodes_vector.get_mut(node_id) {
+            for (target_node_id, connection_data) in node.connections_forward.connections_mut() {
+                if let Some(post_spike_time) = self.last_spike_time(target_node_id) {
+                    handler.plasticity(connection_data, time, post_spike_time);
+                }
+            }
+        }
+
+        self.last_spike_times[node_id] = Some(time);
+
+        for (target_node_id, delay) in delays {
+            self.schedule(time + delay, target_node_id, Some(node_id));
+        }
+
+        Ok(())
+    }
+}
+
+/// Borrow the data of the connection from `source_node_id` to `target_node_id` together with
+/// the data of the target node. None if either node or the connection is gone.
+#[allow(clippy::type_complexity)]
+fn connection_and_target<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>(
+    nodes_vector: &mut [Option<
+        Node<NodeIdT, ConnForwardT, ConnBackwardT, NodeDataT, ConnectionDataT>,
+    >],
+    source_node_id: usize,
+    target_node_id: usize,
+) -> Option<(&ConnectionDataT, &mut NodeDataT)>
+where
+    ConnForwardT: ConnectionsForward<NodeIdT, ConnectionDataT>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+    NodeIdT: UnsignedInt,
+    NodeDataT: Clone + Serialize + DeserializeOwned,
+    ConnectionDataT: Clone + Serialize + DeserializeOwned,
+{
+    let (source_node, target_node) = match source_node_id.cmp(&target_node_id) {
+        Ordering::Equal => {
+            let node = nodes_vector.get_mut(target_node_id)?.as_mut()?;
+            let connections = &node.connections_forward;
+            let connection_index = connections.position(target_node_id)?;
+
+            return Some((&connections.data()[connection_index].data, &mut node.data));
+        }
+        Ordering::Less => {
+            let (head, tail) = nodes_vector.split_at_mut(target_node_id);
+            (head.get(source_node_id)?, tail.first_mut()?)
+        }
+        Ordering::Greater => {
+            let (head, tail) = nodes_vector.split_at_mut(source_node_id);
+            (tail.first()?, head.get_mut(target_node_id)?)
+        }
+    };
+
+    let source_node = source_node.as_ref()?;
+    let target_node = target_node.as_mut()?;
+    let connection_index = source_node.connections_forward.position(target_node_id)?;
+
+    Some((
+        &source_node.connections_forward.data()[connection_index].data,
+        &mut target_node.data,
+    ))
+}
@@ -29,7 +29,7 @@ where
     pub(crate) node_id: NodeIdT,
     pub data: NodeDataT,
 
-    connections_forward: ConnForwardT,
+    pub(crate) connections_forward: ConnForwardT,
     pub(crate) connections_backward: ConnBackwardT,
 
     _phantom_conn_data: PhantomData<ConnectionDataT>,
@@ -1,3 +1,27 @@
+pub mod aggregation;
 pub mod basic_functions;
+pub mod centrality;
+pub mod cliques;
+pub mod clustering;
+pub mod communities;
+pub mod components;
+pub mod critical_path;
+pub mod crossover;
+pub mod cycles;
+pub mod degree_limits;
+pub mod flow;
+pub mod gradients;
+pub mod k_core;
+pub mod layers;
+pub mod mutation;
+pub mod normalization;
+pub mod page_rank;
+pub mod paths;
+pub mod pregel;
+pub mod propagation;
+pub mod reachability;
+pub mod reachability_index;
+pub mod recurrent;
 pub mod save_to_disk;
 pub mod speed_test;
+pub mod spiking;
@@ -0,0 +1,165 @@
+#![cfg(test)]
+
+use crate::error::VeloxGraphError;
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::neural::aggregation::{Aggregation, NeighborDirection};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// INFO: TEST NEIGHBOR AGGREGATION.
+#[test]
+fn test_aggregation_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize,    // NodeIdT
+        Vec<f64>, // NodeT
+        f64,      // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_aggregation(graph);
+}
+
+#[test]
+fn test_aggregation_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16,      // NodeIdT
+        Vec<f64>, // NodeT
+        f64,      // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_aggregation(graph);
+}
+
+fn test_aggregation<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, Vec<f64>, f64>,
+) {
+    // INFO: a random graph with random features, checked against a dense computation.
+    let num_nodes = 15;
+    let num_features = 3;
+    let mut rng = StdRng::seed_from_u64(46);
+    let mut features = Vec::new();
+    for _ in 0..num_nodes {
+        let node_features: Vec<f64> = (0..num_features)
+            .map(|_| rng.random_range(-1.0..1.0))
+            .collect();
+        graph.node_create(node_features.clone());
+        features.push(node_features);
+    }
+
+    let mut weights = vec![vec![None; num_nodes]; num_nodes];
+    for (first_node_id, row) in weights.iter_mut().enumerate() {
+        for (second_node_id, weight) in row.iter_mut().enumerate() {
+            if rng.random_bool(0.2) {
+                let value = rng.random_range(0.1..2.0);
+                *weight = Some(value);
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, value)
+                    .unwrap();
+            }
+        }
+    }
+
+    let aggregations = [
+        Aggregation::Sum,
+        Aggregation::Mean,
+        Aggregation::Max,
+        Aggregation::Attention,
+    ];
+    for direction in [NeighborDirection::Incoming, NeighborDirection::Outgoing] {
+        for aggregation in aggregations {
+            let result = graph
+                .aggregate_neighbors(
+                    aggregation,
+                    direction,
+                    |node_features| node_features.as_slice(),
+                    |weight| *weight,
+                )
+                .unwrap();
+            assert_eq!(result.len(), num_nodes);
+
+            for (node_id, aggregated) in result.iter().enumerate() {
+                let neighbors: Vec<(usize, f64)> = (0..num_nodes)
+                    .filter_map(|other_node_id| {
+                        let weight = match direction {
+                            NeighborDirection::Incoming => weights[other_node_id][node_id],
+                            NeighborDirection::Outgoing => weights[node_id][other_node_id],
+                        };
+                        weight.map(|weight| (other_node_id, weight))
+                    })
+                    .collect();
+
+                let mut expected = vec![0.0; num_features];
+                if !neighbors.is_empty() {
+                    let attentions: Vec<f64> = match aggregation {
+                        Aggregation::Attention => {
+                            let exponentials: Vec<f64> = neighbors
+                                .iter()
+                                .map(|&(other_node_id, _)| {
+                                    let dot: f64 = (0..num_features)
+                                        .map(|index| {
+                                            features[node_id][index]
+                                                * features[other_node_id][index]
+                                        })
+                                        .sum();
+                                    (dot / (num_features as f64).sqrt()).exp()
+                                })
+                                .collect();
+                            let total: f64 = exponentials.iter().sum();
+                            exponentials.iter().map(|value| value / total).collect()
+                        }
+                        _ => vec![1.0; neighbors.len()],
+                    };
+
+                    for (index, value) in expected.iter_mut().enumerate() {
+                        let scaled = neighbors.iter().zip(&attentions).map(
+                            |(&(other_node_id, weight), attention)| {
+                                attention * weight * features[other_node_id][index]
+                            },
+                        );
+                        *value = match aggregation {
+                            Aggregation::Max => scaled.fold(f64::NEG_INFINITY, f64::max),
+                            Aggregation::Mean => scaled.sum::<f64>() / neighbors.len() as f64,
+                            Aggregation::Sum | Aggregation::Attention => scaled.sum(),
+                        };
+                    }
+                }
+
+                assert_eq!(aggregated.len(), num_features);
+                for (value, expected_value) in aggregated.iter().zip(&expected) {
+                    assert!((value - expected_value).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    // INFO: empty slots get an empty vector.
+    graph.node_delete(4).unwrap();
+    let result = graph
+        .aggregate_neighbors(
+            Aggregation::Sum,
+            NeighborDirection::Incoming,
+            |node_features| node_features.as_slice(),
+            |_| 1.0,
+        )
+        .unwrap();
+    assert!(result[4].is_empty());
+    assert_eq!(result[5].len(), num_features);
+
+    // INFO: feature vectors of different lengths are rejected.
+    graph.node_create(vec![1.0]);
+    let result = graph.aggregate_neighbors(
+        Aggregation::Mean,
+        NeighborDirection::Outgoing,
+        |node_features| node_features.as_slice(),
+        |_| 1.0,
+    );
+    assert!(matches!(result, Err(VeloxGraphError::InvalidArgument(_))));
+}
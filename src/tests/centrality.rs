@@ -0,0 +1,195 @@
+#![cfg(test)]
+
+use crate::algorithms::centrality::{CentralitySources, PowerIterationSettings};
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+// INFO: TEST CENTRALITY.
+#[test]
+fn test_centrality_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        f64,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_centrality(graph);
+}
+
+#[test]
+fn test_centrality_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        f64, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_centrality(graph);
+}
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (actual_value, expected_value) in actual.iter().zip(expected) {
+        assert!(
+            (actual_value - expected_value).abs() < 1e-9,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+}
+
+fn test_centrality<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64>,
+) {
+    // INFO: an empty graph converges straight away.
+    let settings = PowerIterationSettings::default();
+    assert!(graph
+        .eigenvector_centrality(&settings, |_| 1.0)
+        .unwrap()
+        .is_empty());
+    assert!(graph
+        .katz_centrality(&settings, 0.1, 1.0, |_| 1.0)
+        .unwrap()
+        .is_empty());
+    let scores = graph.hits(&settings, |_| 1.0).unwrap();
+    assert!(scores.hubs.is_empty() && scores.authorities.is_empty());
+
+    for index in 0..7 {
+        graph.node_create(index);
+    }
+    graph.node_delete(5).unwrap();
+
+    // INFO: a diamond (0 -> 1 -> 3, 0 -> 2 -> 3) followed by 3 -> 4. node6 is isolated.
+    graph.nodes_connection_set(0, 1, 1.0).unwrap();
+    graph.nodes_connection_set(0, 2, 5.0).unwrap();
+    graph.nodes_connection_set(1, 3, 1.0).unwrap();
+    graph.nodes_connection_set(2, 3, 1.0).unwrap();
+    graph.nodes_connection_set(3, 4, 1.0).unwrap();
+
+    let betweenness = graph
+        .betweenness_centrality(&CentralitySources::All)
+        .unwrap();
+    assert_close(&betweenness, &[0.0, 1.0, 1.0, 3.0, 0.0, 0.0, 0.0]);
+
+    // INFO: with costs, the path through node2 is never the shortest one.
+    let betweenness = graph
+        .betweenness_centrality_weighted(&CentralitySources::All, |cost| *cost)
+        .unwrap();
+    assert_close(&betweenness, &[0.0, 2.0, 0.0, 3.0, 0.0, 0.0, 0.0]);
+
+    // INFO: zero costs would hide tied paths, so they are rejected. Self connections are ignored.
+    assert!(graph
+        .betweenness_centrality_weighted(&CentralitySources::All, |cost| *cost - 1.0)
+        .is_err());
+    graph.nodes_connection_set(6, 6, 0.0).unwrap();
+    assert!(graph
+        .betweenness_centrality_weighted(&CentralitySources::All, |cost| *cost)
+        .is_ok());
+    graph.nodes_connection_remove(6, 6).unwrap();
+
+    let centrality = graph.closeness_centrality(&CentralitySources::All).unwrap();
+    assert_close(
+        &centrality.harmonic,
+        &[0.0, 1.0, 1.0, 2.5, 1.0 + 0.5 + 0.5 + 1.0 / 3.0, 0.0, 0.0],
+    );
+    assert_close(
+        &centrality.closeness,
+        &[
+            0.0,
+            1.0 / 5.0,
+            1.0 / 5.0,
+            0.75 * 0.6,
+            (4.0 / 8.0) * 0.8,
+            0.0,
+            0.0,
+        ],
+    );
+
+    let centrality = graph
+        .closeness_centrality_weighted(&CentralitySources::All, |cost| *cost)
+        .unwrap();
+    assert_close(
+        &centrality.harmonic,
+        &[
+            0.0,
+            1.0,
+            0.2,
+            1.0 + 0.5 + 1.0,
+            1.0 + 0.5 + 1.0 / 3.0 + 0.5,
+            0.0,
+            0.0,
+        ],
+    );
+    assert!(graph
+        .closeness_centrality_weighted(&CentralitySources::All, |_| 0.0)
+        .is_err());
+    assert!(graph
+        .closeness_centrality_weighted(&CentralitySources::All, |_| f64::NAN)
+        .is_err());
+
+    // INFO: sampling every node is exact, and sampling fewer is deterministic for a seed.
+    let sampled = graph
+        .betweenness_centrality(&CentralitySources::Sampled {
+            num_sources: 10,
+            seed: 3,
+        })
+        .unwrap();
+    assert_close(&sampled, &[0.0, 1.0, 1.0, 3.0, 0.0, 0.0, 0.0]);
+
+    let sources = CentralitySources::Sampled {
+        num_sources: 3,
+        seed: 3,
+    };
+    let sampled = graph.betweenness_centrality(&sources).unwrap();
+    assert_eq!(sampled.len(), 7);
+    assert_eq!(sampled[5], 0.0);
+    assert_close(&sampled, &graph.betweenness_centrality(&sources).unwrap());
+
+    // INFO: sampling no sources is an error.
+    let sources = CentralitySources::Sampled {
+        num_sources: 0,
+        seed: 3,
+    };
+    assert!(graph.betweenness_centrality(&sources).is_err());
+    assert!(graph.closeness_centrality(&sources).is_err());
+
+    // INFO: power iteration centralities. Connect 4 back to 0 so the graph has a cycle.
+    graph.nodes_connection_set(4, 0, 1.0).unwrap();
+    let settings = PowerIterationSettings {
+        tolerance: 1e-10,
+        max_iterations: 10_000,
+    };
+
+    let eigenvector = graph.eigenvector_centrality(&settings, |_| 1.0).unwrap();
+    let norm: f64 = eigenvector.iter().map(|score| score * score).sum();
+    assert!((norm - 1.0).abs() < 1e-9);
+    assert!(eigenvector[3] > eigenvector[1]);
+    assert_eq!(eigenvector[5], 0.0);
+    assert!(eigenvector[6] < 1e-9);
+
+    let katz = graph.katz_centrality(&settings, 0.1, 1.0, |_| 1.0).unwrap();
+    assert!(katz[3] > katz[1]);
+    assert_eq!(katz[5], 0.0);
+    assert!((katz[6] - 1.0).abs() < 1e-9);
+    // INFO: x3 = beta + alpha * (x1 + x2).
+    assert!((katz[3] - (1.0 + 0.1 * (katz[1] + katz[2]))).abs() < 1e-6);
+    assert!(graph.katz_centrality(&settings, 2.0, 1.0, |_| 1.0).is_err());
+
+    // INFO: node1 and node2 both feed node3, which makes them better hubs than node3.
+    let scores = graph.hits(&settings, |_| 1.0).unwrap();
+    assert!((scores.hubs[1] - scores.hubs[2]).abs() < 1e-9);
+    assert!(scores.hubs[1] > scores.hubs[3]);
+    assert!(scores.authorities[3] > scores.authorities[4]);
+    assert_eq!(scores.hubs[5], 0.0);
+    assert!((scores.hubs.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!((scores.authorities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!((scores.authorities[1] - scores.authorities[2]).abs() < 1e-9);
+}
@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+// INFO: TEST MAXIMAL CLIQUES.
+#[test]
+fn test_cliques_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        u32,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_cliques(graph);
+}
+
+#[test]
+fn test_cliques_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        u32, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_cliques(graph);
+}
+
+fn test_cliques<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, u32>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, u32>,
+) {
+    assert!(graph.maximal_cliques(1).is_empty());
+
+    for index in 0..10 {
+        graph.node_create(index);
+    }
+    graph.node_delete(8).unwrap();
+
+    // INFO: a 4 clique (0, 1, 2, 3) sharing node3 with a triangle (3, 4, 5), a mutual pair
+    // (6, 7) and an isolated node9. Directions are mixed on purpose.
+    for (first_node_id, second_node_id) in [
+        (0, 1),
+        (2, 0),
+        (0, 3),
+        (1, 2),
+        (3, 1),
+        (2, 3),
+        (3, 4),
+        (5, 4),
+        (3, 5),
+        (6, 7),
+        (7, 6),
+        (9, 9),
+    ] {
+        graph
+            .nodes_connection_set(first_node_id, second_node_id, 1)
+            .unwrap();
+    }
+
+    assert_eq!(
+        graph.maximal_cliques(1),
+        vec![vec![0, 1, 2, 3], vec![3, 4, 5], vec![6, 7], vec![9]]
+    );
+    assert_eq!(
+        graph.maximal_cliques(3),
+        vec![vec![0, 1, 2, 3], vec![3, 4, 5]]
+    );
+    assert_eq!(graph.maximal_cliques(4), vec![vec![0, 1, 2, 3]]);
+    assert!(graph.maximal_cliques(5).is_empty());
+
+    let mut sizes = Vec::new();
+    graph.maximal_cliques_for_each(2, |clique| sizes.push(clique.len()));
+    sizes.sort_unstable();
+    assert_eq!(sizes, vec![2, 3, 4]);
+
+    // INFO: removing one connection splits the 4 clique into two triangles.
+    graph.nodes_connection_remove(0, 3).unwrap();
+    assert_eq!(
+        graph.maximal_cliques(3),
+        vec![vec![0, 1, 2], vec![1, 2, 3], vec![3, 4, 5]]
+    );
+}
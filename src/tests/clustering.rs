@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+// INFO: TEST TRIANGLES AND CLUSTERING.
+#[test]
+fn test_clustering_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        u32,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_clustering(graph);
+}
+
+#[test]
+fn test_clustering_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        u32, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_clustering(graph);
+}
+
+fn test_clustering<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, u32>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, u32>,
+) {
+    assert_eq!(graph.transitivity(), 0.0);
+
+    for index in 0..6 {
+        graph.node_create(index);
+    }
+    graph.node_delete(4).unwrap();
+
+    // INFO: a fully bidirectional triangle (0, 1, 2) sharing node2 with a cycle 2 -> 3 -> 5 -> 2.
+    for (first_node_id, second_node_id) in [(0, 1), (1, 2), (2, 0)] {
+        graph
+            .nodes_connection_set(first_node_id, second_node_id, 1)
+            .unwrap();
+        graph
+            .nodes_connection_set(second_node_id, first_node_id, 1)
+            .unwrap();
+    }
+    graph.nodes_connection_set(2, 3, 1).unwrap();
+    graph.nodes_connection_set(3, 5, 1).unwrap();
+    graph.nodes_connection_set(5, 2, 1).unwrap();
+    graph.nodes_connection_set(5, 5, 1).unwrap();
+
+    assert_eq!(graph.triangles(), vec![1, 1, 2, 1, 0, 1]);
+    assert_eq!(graph.triangle_count(), 2);
+    assert_eq!(graph.triangles_directed(), vec![8, 8, 9, 1, 0, 1]);
+
+    let clustering = graph.clustering_coefficients();
+    let expected = [1.0, 1.0, 1.0 / 3.0, 1.0, 0.0, 1.0];
+    for (actual, expected) in clustering.iter().zip(expected) {
+        assert!((actual - expected).abs() < 1e-12);
+    }
+
+    let clustering = graph.clustering_coefficients_directed();
+    let expected = [1.0, 1.0, 18.0 / 52.0, 0.5, 0.0, 0.5];
+    for (actual, expected) in clustering.iter().zip(expected) {
+        assert!((actual - expected).abs() < 1e-12);
+    }
+
+    assert!((graph.transitivity() - 0.6).abs() < 1e-12);
+
+    // INFO: breaking the cycle removes its triangle.
+    graph.nodes_connection_remove(5, 2).unwrap();
+    assert_eq!(graph.triangles(), vec![1, 1, 1, 0, 0, 0]);
+    assert_eq!(graph.triangle_count(), 1);
+}
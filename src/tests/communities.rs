@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+// INFO: TEST COMMUNITY DETECTION.
+#[test]
+fn test_communities_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        f64,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_communities(graph);
+}
+
+#[test]
+fn test_communities_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        f64, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_communities(graph);
+}
+
+fn test_communities<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64>,
+) {
+    for index in 0..9 {
+        graph.node_create(index);
+    }
+    graph.node_delete(3).unwrap();
+
+    // INFO: two triangles (0, 1, 2) and (4, 5, 6) joined by 2 -> 4. node7 and node8 are a pair.
+    for (first_node_id, second_node_id) in [(0, 1), (1, 2), (2, 0), (4, 5), (5, 6), (6, 4), (2, 4)]
+    {
+        graph
+            .nodes_connection_set(first_node_id, second_node_id, 1.0)
+            .unwrap();
+    }
+    graph.nodes_connection_set(7, 8, 1.0).unwrap();
+
+    let expected = vec![
+        Some(0),
+        Some(0),
+        Some(0),
+        None,
+        Some(1),
+        Some(1),
+        Some(1),
+        Some(2),
+        Some(2),
+    ];
+
+    let communities = graph.louvain(|weight| *weight);
+    assert_eq!(communities.assignments, expected);
+    assert_eq!(communities.num_communities, 3);
+    let modularity = graph.modularity(&expected, |weight| *weight);
+    assert!((communities.modularity - modularity).abs() < 1e-12);
+
+    let communities = graph.label_propagation(11, |weight| *weight);
+    assert_eq!(communities.assignments[3], None);
+    assert_eq!(communities.assignments[7], communities.assignments[8]);
+    assert_eq!(communities.assignments[4], communities.assignments[6]);
+    assert_ne!(communities.assignments[0], communities.assignments[7]);
+
+    // INFO: modularity of the two triangles alone: 2 * (3 / 7 - (7 / 14)^2).
+    graph.node_delete(8).unwrap();
+    graph.node_delete(7).unwrap();
+    let assignments = vec![Some(0), Some(0), Some(0), None, Some(1), Some(1), Some(1)];
+    let modularity = graph.modularity(&assignments, |weight| *weight);
+    assert!((modularity - (6.0 / 7.0 - 0.5)).abs() < 1e-12);
+
+    let everything = vec![Some(0); 7];
+    assert!(graph.modularity(&everything, |weight| *weight).abs() < 1e-12);
+
+    // INFO: a heavy bridge pulls node2 to the other side.
+    graph.nodes_connection_set(2, 4, 20.0).unwrap();
+    let communities = graph.louvain(|weight| *weight);
+    assert_eq!(communities.assignments[2], communities.assignments[4]);
+    let unweighted = graph.louvain(|_| 1.0);
+    assert_ne!(unweighted.assignments[2], unweighted.assignments[4]);
+}
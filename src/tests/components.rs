@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+// INFO: TEST WEAKLY CONNECTED COMPONENTS.
+#[test]
+fn test_components_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        u32,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_components(graph);
+}
+
+#[test]
+fn test_components_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        u32, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_components(graph);
+}
+
+fn test_components<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, u32>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, u32>,
+) {
+    assert_eq!(
+        graph.weakly_connected_components(),
+        Vec::<Vec<usize>>::new()
+    );
+    assert!(graph.component_count().is_err());
+
+    graph.component_tracking_enable();
+    assert_eq!(graph.component_count().unwrap(), 0);
+
+    for index in 0..6 {
+        graph.node_create(index);
+    }
+    assert_eq!(graph.component_count().unwrap(), 6);
+
+    // INFO: two chains: 0 -> 1 <- 2 -> 3, and 4 -> 5.
+    graph.nodes_connection_set(0, 1, 1).unwrap();
+    graph.nodes_connection_set(2, 1, 1).unwrap();
+    graph.nodes_connection_set(2, 3, 1).unwrap();
+    graph.nodes_connection_set(4, 5, 1).unwrap();
+
+    assert_eq!(
+        graph.weakly_connected_components(),
+        vec![vec![0, 1, 2, 3], vec![4, 5]]
+    );
+    assert_eq!(graph.component_count().unwrap(), 2);
+    assert!(graph.nodes_same_component(0, 3).unwrap());
+    assert!(!graph.nodes_same_component(3, 4).unwrap());
+
+    // INFO: pruning splits the first chain.
+    graph.nodes_connection_remove(2, 1).unwrap();
+    assert_eq!(
+        graph.weakly_connected_components(),
+        vec![vec![0, 1], vec![2, 3], vec![4, 5]]
+    );
+    assert_eq!(graph.component_count().unwrap(), 3);
+    assert!(!graph.nodes_same_component(0, 3).unwrap());
+
+    // INFO: deleting a bridge node.
+    graph.nodes_connection_set(3, 4, 1).unwrap();
+    assert_eq!(graph.component_count().unwrap(), 2);
+    graph.node_delete(3).unwrap();
+    assert_eq!(
+        graph.weakly_connected_components(),
+        vec![vec![0, 1], vec![2], vec![4, 5]]
+    );
+    assert_eq!(graph.component_count().unwrap(), 3);
+    assert!(graph.component_id(3).is_err());
+
+    // INFO: reusing the empty slot after a rebuild.
+    let node_id = graph.node_create(6);
+    assert_eq!(node_id, 3);
+    assert_eq!(graph.component_count().unwrap(), 4);
+    graph.nodes_connection_set(node_id, 0, 1).unwrap();
+    assert!(graph.nodes_same_component(1, node_id).unwrap());
+    assert_eq!(graph.component_count().unwrap(), 3);
+
+    graph.component_tracking_disable();
+    assert!(!graph.component_tracking_enabled());
+    assert!(graph.component_id(0).is_err());
+}
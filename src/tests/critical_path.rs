@@ -0,0 +1,180 @@
+#![cfg(test)]
+
+use crate::error::VeloxGraphError;
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// INFO: TEST CRITICAL PATH.
+#[test]
+fn test_critical_path_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        u32,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_critical_path(graph);
+}
+
+#[test]
+fn test_critical_path_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        u32, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_critical_path(graph);
+}
+
+/// Cost of every path that starts at `node_id`, including the cost of `node_id` itself.
+fn brute_force_path_costs(
+    connections: &[(usize, usize, u32)],
+    costs: &[u32],
+    node_id: usize,
+) -> Vec<u32> {
+    let mut path_costs = vec![costs[node_id]];
+    for &(first_node_id, second_node_id, weight) in connections {
+        if first_node_id == node_id {
+            for rest in brute_force_path_costs(connections, costs, second_node_id) {
+                path_costs.push(costs[node_id] + weight + rest);
+            }
+        }
+    }
+
+    path_costs
+}
+
+fn test_critical_path<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, u32>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, u32>,
+) {
+    let empty = graph.critical_path(|_| 1.0, |_| 1.0).unwrap();
+    assert_eq!(empty.length, 0.0);
+    assert!(empty.node_ids.is_empty());
+
+    // INFO: a random DAG. Connections only go from lower to higher node ids.
+    let num_nodes = 10;
+    let mut rng = StdRng::seed_from_u64(39);
+    let mut costs = Vec::new();
+    for _ in 0..num_nodes {
+        let cost = rng.random_range(0..5);
+        graph.node_create(cost);
+        costs.push(cost);
+    }
+
+    let mut connections = Vec::new();
+    for first_node_id in 0..num_nodes {
+        for second_node_id in first_node_id + 1..num_nodes {
+            if rng.random_bool(0.3) {
+                let weight = rng.random_range(0..5);
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, weight)
+                    .unwrap();
+                connections.push((first_node_id, second_node_id, weight));
+            }
+        }
+    }
+
+    let critical_path = graph
+        .critical_path(|cost| *cost as f64, |weight| *weight as f64)
+        .unwrap();
+
+    // INFO: the length is the longest path cost, and the reported path has that cost.
+    let all_path_costs: Vec<Vec<u32>> = (0..num_nodes)
+        .map(|node_id| brute_force_path_costs(&connections, &costs, node_id))
+        .collect();
+    let longest = all_path_costs.iter().flatten().max().copied().unwrap();
+    assert_eq!(critical_path.length, longest as f64);
+
+    let mut path_cost = 0;
+    for (index, &node_id) in critical_path.node_ids.iter().enumerate() {
+        path_cost += costs[node_id];
+        if let Some(&next_node_id) = critical_path.node_ids.get(index + 1) {
+            let &(_, _, weight) = connections
+                .iter()
+                .find(|&&(first, second, _)| first == node_id && second == next_node_id)
+                .unwrap();
+            path_cost += weight;
+        }
+    }
+    assert_eq!(path_cost, longest);
+
+    // INFO: earliest start is the longest path into a node, latest start leaves room for the
+    // longest path out of it.
+    for (node_id, path_costs) in all_path_costs.iter().enumerate() {
+        let longest_into = (0..num_nodes)
+            .flat_map(|start_node_id| {
+                let mut into = Vec::new();
+                collect_costs_into(&connections, &costs, start_node_id, node_id, 0, &mut into);
+                into
+            })
+            .max()
+            .unwrap();
+        let longest_out = path_costs.iter().max().copied().unwrap();
+
+        assert_eq!(critical_path.earliest_start[node_id], longest_into as f64);
+        assert_eq!(
+            critical_path.latest_start[node_id],
+            (longest - longest_out) as f64
+        );
+    }
+    for &node_id in &critical_path.node_ids {
+        assert_eq!(critical_path.slack(node_id), 0.0);
+    }
+
+    // INFO: any cycle is an error.
+    graph.nodes_connection_set(num_nodes - 1, 0, 1).unwrap();
+    graph.nodes_connection_set(0, num_nodes - 1, 1).unwrap();
+    match graph.critical_path(|_| 1.0, |_| 1.0) {
+        Err(VeloxGraphError::CycleDetected(node_id)) => {
+            assert!(node_id == 0 || node_id == num_nodes - 1)
+        }
+        _ => panic!("expected a cycle"),
+    }
+
+    let node_id = graph.node_create(0);
+    graph.nodes_connection_set(node_id, node_id, 1).unwrap();
+    graph.nodes_connection_remove(num_nodes - 1, 0).unwrap();
+    assert!(matches!(
+        graph.critical_path(|_| 1.0, |_| 1.0),
+        Err(VeloxGraphError::CycleDetected(cycle_node_id)) if cycle_node_id == node_id
+    ));
+}
+
+/// Cost of every path from `node_id` to `target_node_id`, up to the start of the target.
+fn collect_costs_into(
+    connections: &[(usize, usize, u32)],
+    costs: &[u32],
+    node_id: usize,
+    target_node_id: usize,
+    cost_so_far: u32,
+    into: &mut Vec<u32>,
+) {
+    if node_id == target_node_id {
+        into.push(cost_so_far);
+        return;
+    }
+
+    for &(first_node_id, second_node_id, weight) in connections {
+        if first_node_id == node_id {
+            collect_costs_into(
+                connections,
+                costs,
+                second_node_id,
+                target_node_id,
+                cost_so_far + costs[node_id] + weight,
+                into,
+            );
+        }
+    }
+}
@@ -0,0 +1,179 @@
+#![cfg(test)]
+
+use crate::error::VeloxGraphError;
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::neural::innovation::InnovationRegistry;
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+// INFO: TEST GENOME CROSSOVER.
+#[test]
+fn test_crossover_vec_usize() {
+    test_crossover::<usize, _, _>(VeloxGraphVec::new, 0);
+}
+
+#[test]
+fn test_crossover_hash_u16() {
+    test_crossover::<u16, _, _>(VeloxGraphHash::new, 1);
+}
+
+/// Node data and (first node, second node, weight) connection genes, keyed by innovation number.
+type Genes = (HashMap<usize, u32>, HashMap<usize, (usize, usize, f64)>);
+
+fn genes<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    graph: &VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64>,
+) -> Genes {
+    let mut nodes = HashMap::new();
+    let mut connections = HashMap::new();
+    for (node_id, node) in graph.nodes() {
+        let node_innovation = graph.node_innovation(node_id).unwrap();
+        nodes.insert(node_innovation, node.data);
+
+        for connection in node.connections_forward.data() {
+            let second_node_id = connection.node_id();
+            connections.insert(
+                graph
+                    .connection_innovation(node_id, second_node_id)
+                    .unwrap(),
+                (
+                    node_innovation,
+                    graph.node_innovation(second_node_id).unwrap(),
+                    connection.data,
+                ),
+            );
+        }
+    }
+
+    (nodes, connections)
+}
+
+fn test_crossover<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    graph_new: fn() -> VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64>,
+    seed_offset: u64,
+) {
+    // INFO: two parents evolved from the same minimal genome with a shared registry.
+    let mut registry = InnovationRegistry::new();
+    let initial_innovations: Vec<usize> = (0..4).map(|_| registry.node_innovation_new()).collect();
+    let mut rng = StdRng::seed_from_u64(48 + seed_offset);
+
+    let mut parents = Vec::new();
+    for parent_index in 0..2 {
+        let mut graph = graph_new();
+        for (index, &innovation) in initial_innovations.iter().enumerate() {
+            let node_id = graph.node_create(index as u32);
+            graph.node_innovation_set(node_id, innovation).unwrap();
+        }
+        for (first_node_id, second_node_id) in [(0, 2), (1, 3)] {
+            graph
+                .nodes_connection_set(first_node_id, second_node_id, parent_index as f64)
+                .unwrap();
+            graph
+                .connection_innovation_set(&mut registry, first_node_id, second_node_id)
+                .unwrap();
+        }
+
+        for _ in 0..40 {
+            let weight = rng.random_range(-1.0..1.0);
+            match rng.random_range(0..4) {
+                0 | 1 => {
+                    graph
+                        .mutate_add_connection(&mut registry, &mut rng, weight, false)
+                        .unwrap();
+                }
+                2 => {
+                    let node_data = 10 * (parent_index + 1) + rng.random_range(0..10);
+                    graph
+                        .mutate_split_connection(&mut registry, &mut rng, node_data, |old| {
+                            (1.0, old)
+                        })
+                        .unwrap();
+                }
+                _ => {
+                    graph.mutate_remove_connection(&mut rng).unwrap();
+                }
+            }
+        }
+        parents.push(graph);
+    }
+
+    let (nodes_a, connections_a) = genes(&parents[0]);
+    let (nodes_b, connections_b) = genes(&parents[1]);
+    assert!(connections_a
+        .keys()
+        .any(|key| connections_b.contains_key(key)));
+
+    // INFO: the child has exactly the genes of the fitter parent, with matching genes taken from
+    // either parent, and both parents are picked for some matching genes.
+    let mut picked_other = false;
+    let mut picked_fitter = false;
+    for (fitness_a, fitness_b) in [(1.0, 0.5), (0.5, 1.0), (0.7, 0.7)] {
+        let (fitter_genes, other_genes) = match fitness_b > fitness_a {
+            true => ((&nodes_b, &connections_b), (&nodes_a, &connections_a)),
+            false => ((&nodes_a, &connections_a), (&nodes_b, &connections_b)),
+        };
+
+        for _ in 0..5 {
+            let child =
+                VeloxGraph::crossover(&parents[0], &parents[1], fitness_a, fitness_b, &mut rng)
+                    .unwrap();
+            let (child_nodes, child_connections) = genes(&child);
+
+            assert_eq!(child_nodes.len(), fitter_genes.0.len());
+            for (innovation, data) in &child_nodes {
+                let fitter_data = fitter_genes.0[innovation];
+                match other_genes.0.get(innovation) {
+                    Some(&other_data) => assert!(*data == fitter_data || *data == other_data),
+                    None => assert_eq!(*data, fitter_data),
+                }
+            }
+
+            assert_eq!(child_connections.len(), fitter_genes.1.len());
+            for (innovation, &(first, second, weight)) in &child_connections {
+                let (fitter_first, fitter_second, fitter_weight) = fitter_genes.1[innovation];
+                assert_eq!((first, second), (fitter_first, fitter_second));
+                match other_genes.1.get(innovation) {
+                    Some(&(_, _, other_weight)) if fitter_weight != other_weight => {
+                        picked_fitter |= weight == fitter_weight;
+                        picked_other |= weight == other_weight;
+                        assert!(weight == fitter_weight || weight == other_weight);
+                    }
+                    _ => assert_eq!(weight, fitter_weight),
+                }
+            }
+
+            for (node_id, node) in child.nodes() {
+                for second_node_id in child.forward_ids(node_id) {
+                    assert!(child.backward_ids(second_node_id).any(|id| id == node_id));
+                }
+                assert_eq!(
+                    node.connections_backward.data().len(),
+                    child
+                        .connection_pairs()
+                        .filter(|&(_, second_node_id)| second_node_id == node_id)
+                        .count()
+                );
+            }
+        }
+    }
+    assert!(picked_fitter && picked_other);
+
+    // INFO: nodes without an innovation number cannot be aligned.
+    let mut untagged = graph_new();
+    untagged.node_create(0);
+    let result = VeloxGraph::crossover(&parents[0], &untagged, 1.0, 0.0, &mut rng);
+    assert!(matches!(result, Err(VeloxGraphError::InvalidArgument(_))));
+}
@@ -0,0 +1,151 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// INFO: TEST SIMPLE CYCLES.
+#[test]
+fn test_cycles_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        u32,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_cycles(graph);
+}
+
+#[test]
+fn test_cycles_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        u32, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_cycles(graph);
+}
+
+/// Every simple cycle, starting from its smallest node id, by plain depth first search.
+fn brute_force_cycles(connections: &[(usize, usize)], num_nodes: usize) -> Vec<Vec<usize>> {
+    fn search(connections: &[(usize, usize)], path: &mut Vec<usize>, cycles: &mut Vec<Vec<usize>>) {
+        let start_node_id = path[0];
+        let node_id = *path.last().unwrap();
+
+        for &(first_node_id, second_node_id) in connections {
+            if first_node_id != node_id {
+                continue;
+            }
+            if second_node_id == start_node_id {
+                cycles.push(path.clone());
+            } else if second_node_id > start_node_id && !path.contains(&second_node_id) {
+                path.push(second_node_id);
+                search(connections, path, cycles);
+                path.pop();
+            }
+        }
+    }
+
+    let mut cycles = Vec::new();
+    for start_node_id in 0..num_nodes {
+        search(connections, &mut vec![start_node_id], &mut cycles);
+    }
+
+    cycles.sort_unstable();
+    cycles
+}
+
+fn test_cycles<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, u32>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, u32>,
+) {
+    assert!(graph.simple_cycles(None).is_empty());
+
+    // INFO: a small random graph, checked against brute force for every length bound.
+    let num_nodes = 9;
+    for index in 0..num_nodes {
+        graph.node_create(index as u32);
+    }
+
+    let mut rng = StdRng::seed_from_u64(35);
+    let mut connections = Vec::new();
+    for first_node_id in 0..num_nodes {
+        for second_node_id in 0..num_nodes {
+            if rng.random_bool(0.3) {
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, 1)
+                    .unwrap();
+                connections.push((first_node_id, second_node_id));
+            }
+        }
+    }
+
+    let expected = brute_force_cycles(&connections, num_nodes);
+    assert!(expected.len() > 10);
+    assert_eq!(graph.simple_cycles(None), expected);
+
+    for max_length in 0..=num_nodes {
+        let expected_bounded: Vec<Vec<usize>> = expected
+            .iter()
+            .filter(|cycle| cycle.len() <= max_length)
+            .cloned()
+            .collect();
+        assert_eq!(graph.simple_cycles(Some(max_length)), expected_bounded);
+    }
+
+    let mut num_streamed = 0;
+    graph.simple_cycles_for_each(None, |cycle| {
+        assert!(cycle.iter().all(|&node_id| node_id >= cycle[0]));
+        num_streamed += 1;
+    });
+    assert_eq!(num_streamed, expected.len());
+
+    // INFO: deleting a node removes every cycle through it.
+    graph.node_delete(0).unwrap();
+    assert!(graph
+        .simple_cycles(None)
+        .iter()
+        .all(|cycle| !cycle.contains(&0)));
+}
+
+// INFO: TEST LENGTH BOUND PRUNING.
+#[test]
+fn test_bounded_cycles_pruning() {
+    let mut graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        u32,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    // INFO: a complete graph has far too many cycles to list, so this only finishes if the
+    // bound prunes the search. Every pair and every triple in both directions is a short cycle.
+    let num_nodes = 60;
+    for index in 0..num_nodes {
+        graph.node_create(index as u32);
+    }
+    for first_node_id in 0..num_nodes {
+        for second_node_id in 0..num_nodes {
+            if first_node_id != second_node_id {
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, 1)
+                    .unwrap();
+            }
+        }
+    }
+
+    let mut num_cycles = [0; 4];
+    graph.simple_cycles_for_each(Some(3), |cycle| num_cycles[cycle.len()] += 1);
+
+    let num_pairs = num_nodes * (num_nodes - 1) / 2;
+    let num_triples = num_pairs * (num_nodes - 2) / 3;
+    assert_eq!(num_cycles, [0, 0, num_pairs, 2 * num_triples]);
+}
@@ -0,0 +1,299 @@
+#![cfg(test)]
+
+use crate::degree_limits::{DegreeLimit, EvictionPolicy};
+use crate::error::VeloxGraphError;
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::neural::innovation::InnovationRegistry;
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+// INFO: TEST DEGREE LIMITS.
+#[test]
+fn test_degree_limits_vec_usize() {
+    test_degree_limits::<usize, _, _>(VeloxGraphVec::new);
+}
+
+#[test]
+fn test_degree_limits_hash_u16() {
+    test_degree_limits::<u16, _, _>(VeloxGraphHash::new);
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Policy {
+    Reject,
+    EvictWeakest,
+    EvictOldest,
+}
+
+fn test_degree_limits<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    graph_new: fn() -> VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64>,
+) {
+    let num_nodes = 10;
+    let default_limit = DegreeLimit {
+        max_forward: Some(3),
+        max_backward: Some(2),
+    };
+    let node_limit = DegreeLimit {
+        max_forward: Some(1),
+        max_backward: None,
+    };
+    let mut rng = StdRng::seed_from_u64(49);
+
+    for policy in [Policy::Reject, Policy::EvictWeakest, Policy::EvictOldest] {
+        let mut graph = graph_new();
+        for index in 0..num_nodes {
+            graph.node_create(index);
+        }
+
+        // INFO: the model keeps (weight, age) per connection. Connections from before the
+        // limits have age 0.
+        let mut model: HashMap<(usize, usize), (f64, u64)> = HashMap::new();
+        for (index, second_node_id) in [5, 6, 7, 8].into_iter().enumerate() {
+            let weight = 0.1 * index as f64;
+            graph
+                .nodes_connection_set(4, second_node_id, weight)
+                .unwrap();
+            model.insert((4, second_node_id), (weight, 0));
+        }
+
+        assert!(matches!(
+            graph.degree_limit_set(0, node_limit),
+            Err(VeloxGraphError::DegreeLimitsDisabled)
+        ));
+        graph.degree_limits_enable(
+            default_limit,
+            match policy {
+                Policy::Reject => EvictionPolicy::Reject,
+                Policy::EvictWeakest => {
+                    // INFO: the ordering may capture state.
+                    let sign = 1.0;
+                    EvictionPolicy::evict_weakest(move |first: &f64, second: &f64| {
+                        (sign * first).total_cmp(&(sign * second))
+                    })
+                }
+                Policy::EvictOldest => EvictionPolicy::EvictOldest,
+            },
+        );
+        graph.degree_limit_set(0, node_limit).unwrap();
+        let limit_of = |node_id: usize| match node_id {
+            0 => node_limit,
+            _ => default_limit,
+        };
+
+        let mut age = 0;
+        for _ in 0..300 {
+            let first_node_id = rng.random_range(0..num_nodes as usize);
+            let second_node_id = rng.random_range(0..num_nodes as usize);
+            let weight = rng.random_range(0.0..1.0);
+            let result = graph.nodes_connection_set(first_node_id, second_node_id, weight);
+
+            // INFO: apply the same rules to the model.
+            age += 1;
+            if let Some(entry) = model.get_mut(&(first_node_id, second_node_id)) {
+                entry.0 = weight;
+                assert!(result.is_ok());
+                continue;
+            }
+
+            let forward: Vec<(usize, usize)> = model
+                .keys()
+                .filter(|(first, _)| *first == first_node_id)
+                .copied()
+                .collect();
+            let backward: Vec<(usize, usize)> = model
+                .keys()
+                .filter(|(_, second)| *second == second_node_id)
+                .copied()
+                .collect();
+            let forward_excess = limit_of(first_node_id)
+                .max_forward
+                .map_or(0, |max| (forward.len() + 1).saturating_sub(max));
+            let backward_excess = limit_of(second_node_id)
+                .max_backward
+                .map_or(0, |max| (backward.len() + 1).saturating_sub(max));
+
+            if policy == Policy::Reject && forward_excess > 0 {
+                assert!(matches!(
+                    result,
+                    Err(VeloxGraphError::DegreeLimitReached(node_id)) if node_id == first_node_id
+                ));
+                continue;
+            }
+            if policy == Policy::Reject && backward_excess > 0 {
+                assert!(matches!(
+                    result,
+                    Err(VeloxGraphError::DegreeLimitReached(node_id)) if node_id == second_node_id
+                ));
+                continue;
+            }
+            result.unwrap();
+
+            let mut victims = Vec::new();
+            for (mut candidates, num_excess) in
+                [(forward, forward_excess), (backward, backward_excess)]
+            {
+                candidates.sort_by(|first, second| match policy {
+                    Policy::EvictOldest => model[first].1.cmp(&model[second].1),
+                    _ => model[first].0.total_cmp(&model[second].0),
+                });
+                victims.extend(candidates.into_iter().take(num_excess));
+            }
+            for victim in &victims {
+                if policy == Policy::EvictOldest && model[victim].1 == 0 {
+                    continue;
+                }
+                model.remove(victim);
+                assert!(!graph.has_connection(victim.0, victim.1));
+            }
+            model.insert((first_node_id, second_node_id), (weight, age));
+
+            // INFO: which of the connections from before the limits is the oldest is not defined,
+            // so resync those from the graph.
+            model.retain(|&(first, second), entry| {
+                entry.1 != 0 || graph.has_connection(first, second)
+            });
+            let num_connections: usize = (0..num_nodes as usize)
+                .map(|node_id| graph.forward_ids(node_id).count())
+                .sum();
+            assert_eq!(num_connections, model.len());
+        }
+
+        // INFO: the graph matches the model, stays within its limits and both directions agree.
+        let mut num_connections = 0;
+        for node_id in 0..num_nodes as usize {
+            let forward_ids: Vec<usize> = graph.forward_ids(node_id).collect();
+            let backward_ids: Vec<usize> = graph.backward_ids(node_id).collect();
+            num_connections += forward_ids.len();
+            for &second_node_id in &forward_ids {
+                assert!(graph.backward_ids(second_node_id).any(|id| id == node_id));
+                assert_eq!(
+                    graph.connection_data(node_id, second_node_id).copied(),
+                    model.get(&(node_id, second_node_id)).map(|entry| entry.0)
+                );
+            }
+            for &first_node_id in &backward_ids {
+                assert!(graph.has_connection(first_node_id, node_id));
+            }
+
+            let limit = limit_of(node_id);
+            if node_id != 4 {
+                assert!(forward_ids.len() <= limit.max_forward.unwrap());
+            }
+            if let Some(max_backward) = limit.max_backward {
+                assert!(backward_ids.len() <= max_backward);
+            }
+        }
+        assert_eq!(num_connections, model.len());
+        assert_eq!(graph.degree_limit(0).unwrap(), node_limit);
+
+        // INFO: a limit of 0 has nothing to evict, so it always rejects.
+        graph
+            .degree_limit_set(
+                1,
+                DegreeLimit {
+                    max_forward: Some(0),
+                    max_backward: None,
+                },
+            )
+            .unwrap();
+        for second_node_id in 0..num_nodes as usize {
+            if !graph.has_connection(1, second_node_id) {
+                assert!(matches!(
+                    graph.nodes_connection_set(1, second_node_id, 1.0),
+                    Err(VeloxGraphError::DegreeLimitReached(1))
+                ));
+            }
+        }
+
+        // INFO: deleting a node drops its own limit.
+        graph.node_delete(0).unwrap();
+        let node_id = graph.node_create(0);
+        assert_eq!(node_id, 0);
+        assert_eq!(graph.degree_limit(0).unwrap(), default_limit);
+
+        graph.degree_limits_disable();
+        assert!(!graph.degree_limits_enabled());
+        for second_node_id in 0..num_nodes as usize {
+            graph.nodes_connection_set(1, second_node_id, 1.0).unwrap();
+        }
+    }
+}
+
+// INFO: TEST DEGREE LIMITS WITH SPLIT MUTATIONS.
+#[test]
+fn test_degree_limits_split_vec_usize() {
+    test_degree_limits_split::<usize, _, _>(VeloxGraphVec::new);
+}
+
+#[test]
+fn test_degree_limits_split_hash_u16() {
+    test_degree_limits_split::<u16, _, _>(VeloxGraphHash::new);
+}
+
+fn test_degree_limits_split<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    graph_new: fn() -> VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64>,
+) {
+    let limit = DegreeLimit {
+        max_forward: None,
+        max_backward: Some(1),
+    };
+    let policy = EvictionPolicy::Reject;
+    assert_eq!(format!("{:?}", policy.clone()), "Reject");
+
+    for policy in [policy, EvictionPolicy::EvictOldest] {
+        // INFO: 0 -> 1 can be split, 2 -> 1 cannot since node2 has no innovation number.
+        let mut graph = graph_new();
+        let mut registry = InnovationRegistry::new();
+        for index in 0..3 {
+            graph.node_create(index);
+        }
+        for node_id in 0..2 {
+            graph
+                .node_innovation_set(node_id, registry.node_innovation_new())
+                .unwrap();
+        }
+        graph.nodes_connection_set(0, 1, 0.5).unwrap();
+        graph.nodes_connection_set(2, 1, 0.5).unwrap();
+        graph.degree_limits_enable(limit, policy.clone());
+
+        // INFO: node1 is full once 0 -> 1 is gone, because of 2 -> 1.
+        let mut rng = StdRng::seed_from_u64(49);
+        let result =
+            graph.mutate_split_connection(&mut registry, &mut rng, 9, |weight| (1.0, weight));
+
+        match policy {
+            EvictionPolicy::Reject => {
+                assert!(matches!(
+                    result,
+                    Err(VeloxGraphError::DegreeLimitReached(1))
+                ));
+                assert_eq!(graph.num_entries(), 3);
+                assert!(graph.has_connection(0, 1));
+                assert!(graph.has_connection(2, 1));
+                assert_eq!(graph.connection_innovation(0, 1), None);
+            }
+            _ => {
+                let new_node_id = result.unwrap().unwrap();
+                assert_eq!(new_node_id, 3);
+                assert!(!graph.has_connection(0, 1));
+                assert!(!graph.has_connection(2, 1));
+                assert!(graph.has_connection(0, new_node_id));
+                assert!(graph.has_connection(new_node_id, 1));
+            }
+        }
+    }
+}
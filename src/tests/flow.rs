@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+// INFO: TEST MAX FLOW AND MIN CUT.
+#[test]
+fn test_flow_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        f64,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_flow(graph);
+}
+
+#[test]
+fn test_flow_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        f64, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_flow(graph);
+}
+
+fn test_flow<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64>,
+) {
+    for index in 0..8 {
+        graph.node_create(index);
+    }
+    graph.node_delete(6).unwrap();
+
+    // INFO: the textbook network from CLRS, source 0 and sink 5. node7 is unrelated.
+    for (first_node_id, second_node_id, capacity) in [
+        (0, 1, 16.0),
+        (0, 2, 13.0),
+        (1, 3, 12.0),
+        (2, 1, 4.0),
+        (2, 4, 14.0),
+        (3, 2, 9.0),
+        (3, 5, 20.0),
+        (4, 3, 7.0),
+        (4, 5, 4.0),
+        (7, 0, 3.0),
+    ] {
+        graph
+            .nodes_connection_set(first_node_id, second_node_id, capacity)
+            .unwrap();
+    }
+
+    let max_flow = graph.max_flow(0, 5, |capacity| *capacity).unwrap();
+    assert!((max_flow.value - 23.0).abs() < 1e-9);
+    assert_eq!(max_flow.source_side, vec![0, 1, 2, 4]);
+    assert_eq!(max_flow.sink_side, vec![3, 5, 7]);
+    assert_eq!(max_flow.cut_connections, vec![(1, 3), (4, 3), (4, 5)]);
+
+    // INFO: every flow respects its capacity, and flow is conserved at inner nodes.
+    let mut balances = [0.0; 8];
+    for &(first_node_id, second_node_id, flow) in &max_flow.flows {
+        let node = graph.node_get(first_node_id).unwrap();
+        let capacity = node.connections_forward().get(second_node_id).unwrap().data;
+        assert!(flow <= capacity + 1e-9);
+        balances[first_node_id] -= flow;
+        balances[second_node_id] += flow;
+    }
+    assert!((balances[0] + 23.0).abs() < 1e-9);
+    assert!((balances[5] - 23.0).abs() < 1e-9);
+    for node_id in [1, 2, 3, 4, 7] {
+        assert!(balances[node_id].abs() < 1e-9);
+    }
+
+    // INFO: unit capacities count the fewest connections to remove.
+    let max_flow = graph.max_flow(0, 5, |_| 1.0).unwrap();
+    assert!((max_flow.value - 2.0).abs() < 1e-9);
+    assert_eq!(max_flow.cut_connections.len(), 2);
+
+    // INFO: no path at all.
+    let max_flow = graph.max_flow(5, 0, |capacity| *capacity).unwrap();
+    assert_eq!(max_flow.value, 0.0);
+    assert!(max_flow.flows.is_empty());
+    assert_eq!(max_flow.source_side, vec![5]);
+
+    assert!(graph.max_flow(0, 0, |capacity| *capacity).is_err());
+    assert!(graph.max_flow(0, 6, |capacity| *capacity).is_err());
+}
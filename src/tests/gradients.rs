@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// INFO: TEST SPARSE GRADIENT ACCUMULATION.
+#[test]
+fn test_gradients_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        f64,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_gradients(graph);
+}
+
+#[test]
+fn test_gradients_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        f64, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_gradients(graph);
+}
+
+fn test_gradients<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64>,
+) {
+    // INFO: a random sparse layer, checked against a dense weight matrix.
+    let num_nodes = 30;
+    for index in 0..num_nodes {
+        graph.node_create(index as u32);
+    }
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut weights = vec![vec![0.0; num_nodes]; num_nodes];
+    for (first_node_id, row) in weights.iter_mut().enumerate() {
+        for (second_node_id, weight) in row.iter_mut().enumerate() {
+            if rng.random_bool(0.1) {
+                *weight = rng.random_range(-1.0..1.0);
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, *weight)
+                    .unwrap();
+            }
+        }
+    }
+
+    let input_activations: Vec<f64> = (0..num_nodes).map(|_| rng.random_range(0.0..2.0)).collect();
+    let mut output_gradients = vec![0.0; num_nodes];
+    for gradient in output_gradients.iter_mut() {
+        if rng.random_bool(0.5) {
+            *gradient = rng.random_range(-1.0..1.0);
+        }
+    }
+
+    let learning_rate = 0.1;
+    let mut num_updates = 0;
+    let input_gradients = graph
+        .backpropagate(
+            &input_activations,
+            &output_gradients,
+            |weight| *weight,
+            |weight, gradient| {
+                *weight -= learning_rate * gradient;
+                num_updates += 1;
+            },
+        )
+        .unwrap();
+
+    // INFO: only connections into nodes with a gradient are visited.
+    let expected_num_updates = weights
+        .iter()
+        .flat_map(|row| row.iter().zip(&output_gradients))
+        .filter(|&(&weight, &gradient)| weight != 0.0 && gradient != 0.0)
+        .count();
+    assert_eq!(num_updates, expected_num_updates);
+
+    for (first_node_id, row) in weights.iter().enumerate() {
+        let expected_input_gradient: f64 = row
+            .iter()
+            .zip(&output_gradients)
+            .map(|(weight, gradient)| weight * gradient)
+            .sum();
+        assert!((input_gradients[first_node_id] - expected_input_gradient).abs() < 1e-12);
+
+        for (second_node_id, &weight) in row.iter().enumerate() {
+            if weight == 0.0 {
+                continue;
+            }
+
+            let expected_weight = weight
+                - learning_rate
+                    * input_activations[first_node_id]
+                    * output_gradients[second_node_id];
+            let new_weight = graph
+                .node_get(first_node_id)
+                .unwrap()
+                .connections_forward()
+                .get(second_node_id)
+                .unwrap()
+                .data;
+            assert!((new_weight - expected_weight).abs() < 1e-12);
+        }
+    }
+
+    // INFO: more gradients than slots is an error.
+    assert!(graph
+        .backpropagate(&[], &vec![1.0; num_nodes + 1], |weight| *weight, |_, _| {})
+        .is_err());
+}
@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+use crate::algorithms::k_core::CoreDegree;
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+// INFO: TEST K-CORE DECOMPOSITION.
+#[test]
+fn test_k_core_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        u32,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_k_core(graph);
+}
+
+#[test]
+fn test_k_core_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        u32, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_k_core(graph);
+}
+
+fn test_k_core<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, u32>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, u32>,
+) {
+    assert_eq!(graph.core_numbers(CoreDegree::Total), Vec::<usize>::new());
+
+    for index in 0..7 {
+        graph.node_create(index);
+    }
+    graph.node_delete(5).unwrap();
+
+    // INFO: a fully bidirectional triangle (0, 1, 2), with 3 -> 0, 0 -> 4 and a self connection.
+    for (first_node_id, second_node_id) in [(0, 1), (1, 2), (2, 0)] {
+        graph
+            .nodes_connection_set(first_node_id, second_node_id, 1)
+            .unwrap();
+        graph
+            .nodes_connection_set(second_node_id, first_node_id, 1)
+            .unwrap();
+    }
+    graph.nodes_connection_set(3, 0, 1).unwrap();
+    graph.nodes_connection_set(0, 4, 1).unwrap();
+    graph.nodes_connection_set(6, 6, 1).unwrap();
+
+    assert_eq!(
+        graph.core_numbers(CoreDegree::Total),
+        vec![4, 4, 4, 1, 1, 0, 0]
+    );
+    assert_eq!(
+        graph.core_numbers(CoreDegree::In),
+        vec![2, 2, 2, 0, 1, 0, 0]
+    );
+    assert_eq!(
+        graph.core_numbers(CoreDegree::Out),
+        vec![2, 2, 2, 1, 0, 0, 0]
+    );
+
+    assert_eq!(graph.k_core(2, CoreDegree::Total), vec![0, 1, 2]);
+    assert_eq!(graph.k_core(1, CoreDegree::In), vec![0, 1, 2, 4]);
+    assert_eq!(graph.k_core(0, CoreDegree::Out), vec![0, 1, 2, 3, 4, 6]);
+
+    // INFO: pruning one side of the triangle shrinks the nucleus.
+    graph.nodes_connection_remove(1, 2).unwrap();
+    graph.nodes_connection_remove(2, 1).unwrap();
+    assert_eq!(
+        graph.core_numbers(CoreDegree::Total),
+        vec![2, 2, 2, 1, 1, 0, 0]
+    );
+}
@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use crate::error::VeloxGraphError;
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// INFO: TEST LAYER ASSIGNMENT.
+#[test]
+fn test_layers_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        u32,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_layers(graph);
+}
+
+#[test]
+fn test_layers_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        u32, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_layers(graph);
+}
+
+fn test_layers<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, u32>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, u32>,
+) {
+    assert!(graph.layer_assignment().unwrap().is_empty());
+
+    // INFO: a random DAG. Connections only go from lower to higher node ids.
+    let num_nodes = 20;
+    for index in 0..num_nodes {
+        graph.node_create(index as u32);
+    }
+
+    let mut rng = StdRng::seed_from_u64(40);
+    for first_node_id in 0..num_nodes {
+        for second_node_id in first_node_id + 1..num_nodes {
+            if rng.random_bool(0.15) {
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, 1)
+                    .unwrap();
+            }
+        }
+    }
+
+    let layers = graph.layer_assignment().unwrap();
+    let mut depths = vec![usize::MAX; num_nodes];
+    for (depth, layer) in layers.iter().enumerate() {
+        assert!(!layer.is_empty());
+        assert!(layer.windows(2).all(|pair| pair[0] < pair[1]));
+        for &node_id in layer {
+            assert_eq!(depths[node_id], usize::MAX);
+            depths[node_id] = depth;
+        }
+    }
+
+    // INFO: every node is one deeper than its deepest predecessor, sources are at depth 0.
+    for (node_id, &depth) in depths.iter().enumerate() {
+        let predecessor_depth = graph
+            .backward_ids(node_id)
+            .map(|predecessor_id| depths[predecessor_id] + 1)
+            .max();
+        assert_eq!(depth, predecessor_depth.unwrap_or(0));
+    }
+
+    // INFO: deleted nodes are skipped.
+    graph.node_delete(3).unwrap();
+    let layers = graph.layer_assignment().unwrap();
+    assert_eq!(layers.iter().map(Vec::len).sum::<usize>(), num_nodes - 1);
+    assert!(layers.iter().all(|layer| !layer.contains(&3)));
+
+    // INFO: a recurrent connection is an error.
+    graph.nodes_connection_set(num_nodes - 1, 0, 1).unwrap();
+    graph.nodes_connection_set(0, num_nodes - 1, 1).unwrap();
+    assert!(matches!(
+        graph.layer_assignment(),
+        Err(VeloxGraphError::CycleDetected(_))
+    ));
+}
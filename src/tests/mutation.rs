@@ -0,0 +1,256 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::neural::innovation::InnovationRegistry;
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+// INFO: TEST NEAT STYLE MUTATIONS.
+#[test]
+fn test_mutation_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        f64,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_mutation(graph, VeloxGraphVec::new());
+}
+
+#[test]
+fn test_mutation_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        f64, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_mutation(graph, VeloxGraphHash::new());
+}
+
+fn test_mutation<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64>,
+    mut other_graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64>,
+) {
+    // INFO: three inputs and two outputs, tagged the same way in both graphs.
+    let mut registry = InnovationRegistry::new();
+    for index in 0..5 {
+        let innovation = registry.node_innovation_new();
+        let node_id = graph.node_create(index);
+        graph.node_innovation_set(node_id, innovation).unwrap();
+        let node_id = other_graph.node_create(index);
+        other_graph
+            .node_innovation_set(node_id, innovation)
+            .unwrap();
+    }
+
+    // INFO: random cycle free mutations keep the graph acyclic and fully tagged.
+    let mut rng = StdRng::seed_from_u64(47);
+    for _ in 0..300 {
+        match rng.random_range(0..10) {
+            0..=5 => {
+                let added = graph
+                    .mutate_add_connection(&mut registry, &mut rng, 1.0, false)
+                    .unwrap();
+                if let Some((first_node_id, second_node_id)) = added {
+                    assert_ne!(first_node_id, second_node_id);
+                }
+            }
+            6..=7 => {
+                graph
+                    .mutate_split_connection(&mut registry, &mut rng, 9, |weight| (1.0, weight))
+                    .unwrap();
+            }
+            _ => {
+                graph.mutate_remove_connection(&mut rng).unwrap();
+            }
+        }
+
+        assert!(graph.topological_order().is_ok());
+
+        let mut node_innovations = HashSet::new();
+        for node_id in graph.node_ids().collect::<Vec<_>>() {
+            let node_innovation = graph.node_innovation(node_id).unwrap();
+            assert!(node_innovations.insert(node_innovation));
+
+            for second_node_id in graph.forward_ids(node_id).collect::<Vec<_>>() {
+                assert!(graph.backward_ids(second_node_id).any(|id| id == node_id));
+                let expected = registry.connection_innovation(
+                    node_innovation,
+                    graph.node_innovation(second_node_id).unwrap(),
+                );
+                assert_eq!(
+                    graph.connection_innovation(node_id, second_node_id),
+                    Some(expected)
+                );
+            }
+        }
+    }
+    assert!(graph.num_entries() > 5);
+
+    // INFO: the same split in another graph of the population gets the same numbers.
+    other_graph.nodes_connection_set(0, 3, 1.0).unwrap();
+    other_graph
+        .connection_innovation_set(&mut registry, 0, 3)
+        .unwrap();
+    let mut first_graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64> =
+        VeloxGraph::new();
+    for index in 0..5 {
+        let node_id = first_graph.node_create(index);
+        first_graph
+            .node_innovation_set(node_id, other_graph.node_innovation(node_id).unwrap())
+            .unwrap();
+    }
+    first_graph.nodes_connection_set(0, 3, 1.0).unwrap();
+
+    let new_node_id = first_graph
+        .mutate_split_connection(&mut registry, &mut rng, 9, |weight| (1.0, weight))
+        .unwrap()
+        .unwrap();
+    let other_new_node_id = other_graph
+        .mutate_split_connection(&mut registry, &mut rng, 9, |weight| (1.0, weight))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        first_graph.node_innovation(new_node_id),
+        other_graph.node_innovation(other_new_node_id)
+    );
+    assert_eq!(
+        first_graph.connection_innovation(0, new_node_id),
+        other_graph.connection_innovation(0, other_new_node_id)
+    );
+    assert_eq!(first_graph.connection_innovation(0, 3), None);
+
+    // INFO: splitting the same connection again gives a new node number.
+    first_graph.nodes_connection_set(0, 3, 1.0).unwrap();
+    first_graph.nodes_connection_remove(0, new_node_id).unwrap();
+    first_graph.nodes_connection_remove(new_node_id, 3).unwrap();
+    let second_new_node_id = first_graph
+        .mutate_split_connection(&mut registry, &mut rng, 9, |weight| (1.0, weight))
+        .unwrap()
+        .unwrap();
+    assert_ne!(
+        first_graph.node_innovation(second_new_node_id),
+        first_graph.node_innovation(new_node_id)
+    );
+
+    // INFO: the repeated split is recorded, so the other graph gets the same new number too.
+    other_graph.nodes_connection_set(0, 3, 1.0).unwrap();
+    other_graph
+        .nodes_connection_remove(0, other_new_node_id)
+        .unwrap();
+    other_graph
+        .nodes_connection_remove(other_new_node_id, 3)
+        .unwrap();
+    let other_second_new_node_id = other_graph
+        .mutate_split_connection(&mut registry, &mut rng, 9, |weight| (1.0, weight))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        other_graph.node_innovation(other_second_new_node_id),
+        first_graph.node_innovation(second_new_node_id)
+    );
+    let second_innovation = first_graph.node_innovation(second_new_node_id).unwrap();
+    assert_eq!(
+        first_graph.node_id_with_innovation(second_innovation),
+        Some(second_new_node_id)
+    );
+
+    // INFO: with cycles allowed every pair fills up, self connections included. Untagged nodes
+    // are left out.
+    let mut cyclic_graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64> =
+        VeloxGraph::new();
+    for index in 0..4 {
+        let node_id = cyclic_graph.node_create(index);
+        cyclic_graph
+            .node_innovation_set(node_id, registry.node_innovation_new())
+            .unwrap();
+    }
+    let untagged_node_id = cyclic_graph.node_create(4);
+    let mut num_added = 0;
+    while cyclic_graph
+        .mutate_add_connection(&mut registry, &mut rng, 1.0, true)
+        .unwrap()
+        .is_some()
+    {
+        num_added += 1;
+    }
+    assert_eq!(num_added, 16);
+    assert!(cyclic_graph.has_connection(2, 2));
+    assert_eq!(cyclic_graph.forward_ids(untagged_node_id).count(), 0);
+    assert_eq!(cyclic_graph.backward_ids(untagged_node_id).count(), 0);
+
+    // INFO: deleting a node drops its numbers and those of its connections.
+    cyclic_graph.node_delete(1).unwrap();
+    assert_eq!(cyclic_graph.node_innovation(1), None);
+    assert_eq!(cyclic_graph.connection_innovation(0, 1), None);
+    assert!(cyclic_graph.connection_innovation(0, 2).is_some());
+
+    let mut num_removed = 0;
+    while cyclic_graph
+        .mutate_remove_connection(&mut rng)
+        .unwrap()
+        .is_some()
+    {
+        num_removed += 1;
+    }
+    assert_eq!(num_removed, 9);
+    assert_eq!(cyclic_graph.connection_innovation(0, 2), None);
+
+    // INFO: without cycles the nodes fill up to a full order.
+    let mut acyclic_graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64> =
+        VeloxGraph::new();
+    for index in 0..5 {
+        let node_id = acyclic_graph.node_create(index);
+        acyclic_graph
+            .node_innovation_set(node_id, registry.node_innovation_new())
+            .unwrap();
+    }
+    let mut num_added = 0;
+    while acyclic_graph
+        .mutate_add_connection(&mut registry, &mut rng, 1.0, false)
+        .unwrap()
+        .is_some()
+    {
+        num_added += 1;
+    }
+    assert_eq!(num_added, 10);
+    assert!(acyclic_graph.topological_order().is_ok());
+
+    // INFO: every free pair is picked about equally often.
+    let mut counts = [0; 4];
+    for _ in 0..4000 {
+        let pair = first_connection_pair(&mut registry, &mut rng);
+        counts[pair] += 1;
+    }
+    assert!(counts.iter().all(|&count| (800..1200).contains(&count)));
+}
+
+/// Add one connection to a fresh graph of two tagged nodes with cycles allowed, and return which
+/// of the four pairs was picked.
+fn first_connection_pair(registry: &mut InnovationRegistry, rng: &mut StdRng) -> usize {
+    let mut graph: VeloxGraphVec<usize, u32, f64> = VeloxGraphVec::new();
+    for index in 0..2 {
+        let node_id = graph.node_create(index);
+        graph
+            .node_innovation_set(node_id, registry.node_innovation_new())
+            .unwrap();
+    }
+
+    let (first_node_id, second_node_id) = graph
+        .mutate_add_connection(registry, rng, 1.0, true)
+        .unwrap()
+        .unwrap();
+
+    first_node_id * 2 + second_node_id
+}
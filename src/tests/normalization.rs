@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::neural::aggregation::NeighborDirection;
+use crate::neural::normalization::Normalization;
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// INFO: TEST WEIGHT NORMALIZATION.
+#[test]
+fn test_normalization_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize,      // NodeIdT
+        u32,        // NodeT
+        (u32, f64), // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_normalization(graph);
+}
+
+#[test]
+fn test_normalization_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16,        // NodeIdT
+        u32,        // NodeT
+        (u32, f64), // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_normalization(graph);
+}
+
+fn test_normalization<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, (u32, f64)>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, (u32, f64)>,
+) {
+    // INFO: a random graph whose connections carry a tag next to the weight, checked against a
+    // dense computation.
+    let num_nodes = 15;
+    let mut rng = StdRng::seed_from_u64(50);
+    for index in 0..num_nodes {
+        graph.node_create(index);
+    }
+
+    let mut weights = vec![vec![None; num_nodes as usize]; num_nodes as usize];
+    for (first_node_id, row) in weights.iter_mut().enumerate() {
+        for (second_node_id, weight) in row.iter_mut().enumerate() {
+            if rng.random_bool(0.25) {
+                let value = rng.random_range(-2.0..2.0);
+                *weight = Some(value);
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, (7, value))
+                    .unwrap();
+            }
+        }
+    }
+
+    // INFO: node1 only has zero weights going out, which stay as they are.
+    for (second_node_id, weight) in weights[1].iter_mut().enumerate() {
+        if weight.is_some() {
+            *weight = Some(0.0);
+            graph
+                .nodes_connection_set(1, second_node_id, (7, 0.0))
+                .unwrap();
+        }
+    }
+
+    let normalizations = [
+        Normalization::L1,
+        Normalization::L2,
+        Normalization::Softmax,
+        Normalization::Max,
+    ];
+    for direction in [NeighborDirection::Outgoing, NeighborDirection::Incoming] {
+        for normalization in normalizations {
+            let mut expected = weights.clone();
+            for owner_id in 0..num_nodes as usize {
+                let cells: Vec<(usize, usize)> = (0..num_nodes as usize)
+                    .map(|other_id| match direction {
+                        NeighborDirection::Outgoing => (owner_id, other_id),
+                        NeighborDirection::Incoming => (other_id, owner_id),
+                    })
+                    .filter(|&(first, second)| weights[first][second].is_some())
+                    .collect();
+                let values: Vec<f64> = cells
+                    .iter()
+                    .map(|&(first, second)| weights[first][second].unwrap())
+                    .collect();
+
+                let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                let total: f64 = match normalization {
+                    Normalization::L1 => values.iter().map(|value| value.abs()).sum(),
+                    Normalization::L2 => {
+                        values.iter().map(|value| value * value).sum::<f64>().sqrt()
+                    }
+                    Normalization::Softmax => values.iter().map(|value| (value - max).exp()).sum(),
+                    Normalization::Max => values
+                        .iter()
+                        .fold(0.0, |total, value| value.abs().max(total)),
+                };
+                if total == 0.0 {
+                    continue;
+                }
+
+                for (&(first, second), value) in cells.iter().zip(&values) {
+                    expected[first][second] = Some(match normalization {
+                        Normalization::Softmax => (value - max).exp() / total,
+                        _ => value / total,
+                    });
+                }
+            }
+
+            let mut copy: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, (u32, f64)> =
+                VeloxGraph::new();
+            for index in 0..num_nodes {
+                copy.node_create(index);
+            }
+            for (first_node_id, row) in weights.iter().enumerate() {
+                for (second_node_id, weight) in row.iter().enumerate() {
+                    if let Some(weight) = weight {
+                        copy.nodes_connection_set(first_node_id, second_node_id, (7, *weight))
+                            .unwrap();
+                    }
+                }
+            }
+
+            copy.normalize_weights(
+                normalization,
+                direction,
+                |(_, weight)| *weight,
+                |(_, weight), normalized| *weight = normalized,
+            );
+
+            for (first_node_id, row) in expected.iter().enumerate() {
+                for (second_node_id, expected_weight) in row.iter().enumerate() {
+                    let connection_data = copy.connection_data(first_node_id, second_node_id);
+                    match (connection_data, expected_weight) {
+                        (Some(&(tag, weight)), Some(expected_weight)) => {
+                            assert_eq!(tag, 7);
+                            assert!((weight - expected_weight).abs() < 1e-12);
+                        }
+                        (None, None) => {}
+                        _ => panic!("connection {} -> {} changed", first_node_id, second_node_id),
+                    }
+                }
+            }
+        }
+    }
+
+    // INFO: normalized outgoing softmax weights of a node sum to 1.
+    graph.normalize_weights(
+        Normalization::Softmax,
+        NeighborDirection::Outgoing,
+        |(_, weight)| *weight,
+        |(_, weight), normalized| *weight = normalized,
+    );
+    for node_id in 0..num_nodes as usize {
+        let sum: f64 = graph
+            .forward_ids(node_id)
+            .map(|second_node_id| graph.connection_data(node_id, second_node_id).unwrap().1)
+            .sum();
+        if graph.forward_ids(node_id).count() > 0 {
+            assert!((sum - 1.0).abs() < 1e-12);
+        }
+    }
+}
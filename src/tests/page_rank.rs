@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use crate::algorithms::page_rank::PageRankSettings;
+use crate::error::VeloxGraphError;
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+// INFO: TEST PAGERANK.
+#[test]
+fn test_page_rank_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        f64,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_page_rank(graph);
+}
+
+#[test]
+fn test_page_rank_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        f64, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_page_rank(graph);
+}
+
+fn test_page_rank<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64>,
+) {
+    let settings = PageRankSettings {
+        tolerance: 1e-12,
+        max_iterations: 1000,
+        ..PageRankSettings::default()
+    };
+
+    for index in 0..5 {
+        graph.node_create(index);
+    }
+
+    // INFO: a 3 cycle (0 -> 1 -> 2 -> 0) is symmetric, so every node gets the same rank.
+    graph.nodes_connection_set(0, 1, 1.0).unwrap();
+    graph.nodes_connection_set(1, 2, 1.0).unwrap();
+    graph.nodes_connection_set(2, 0, 1.0).unwrap();
+    graph.node_delete(4).unwrap();
+    graph.node_delete(3).unwrap();
+
+    let ranks = graph.page_rank(&settings).unwrap();
+    for rank in &ranks {
+        assert!((rank - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    // INFO: an empty slot in the middle and a dangling node.
+    graph.node_create(3);
+    graph.node_create(4);
+    graph.node_delete(3).unwrap();
+    graph.nodes_connection_set(2, 4, 3.0).unwrap();
+
+    let ranks = graph.page_rank(&settings).unwrap();
+    assert_eq!(ranks.len(), 5);
+    assert_eq!(ranks[3], 0.0);
+    let total: f64 = ranks.iter().sum();
+    assert!((total - 1.0).abs() < 1e-9);
+
+    // INFO: weighting 2 -> 4 heavier than 2 -> 0 moves rank to node 4.
+    let unweighted_rank4 = ranks[4];
+    let ranks = graph
+        .page_rank_weighted(&settings, |weight| *weight)
+        .unwrap();
+    assert!(ranks[4] > unweighted_rank4);
+    let total: f64 = ranks.iter().sum();
+    assert!((total - 1.0).abs() < 1e-9);
+
+    // INFO: personalized rank from a seed never reaches nodes that are unreachable from it.
+    let node_id5 = graph.node_create(5);
+    graph.nodes_connection_set(4, node_id5, 1.0).unwrap();
+    let ranks = graph
+        .page_rank_personalized(&settings, &[4], |weight| *weight)
+        .unwrap();
+    assert!(ranks[4] > ranks[node_id5]);
+    assert!(ranks[node_id5] > 0.0);
+    assert_eq!(ranks[0], 0.0);
+    let total: f64 = ranks.iter().sum();
+    assert!((total - 1.0).abs() < 1e-9);
+
+    assert!(graph
+        .page_rank_personalized(&settings, &[], |weight| *weight)
+        .is_err());
+    assert!(graph
+        .page_rank_personalized(&settings, &[7], |weight| *weight)
+        .is_err());
+
+    // INFO: invalid settings, and running out of iterations, are errors.
+    for invalid_settings in [
+        PageRankSettings {
+            damping_factor: 1.5,
+            ..settings.clone()
+        },
+        PageRankSettings {
+            tolerance: 0.0,
+            ..settings.clone()
+        },
+    ] {
+        assert!(matches!(
+            graph.page_rank(&invalid_settings),
+            Err(VeloxGraphError::InvalidArgument(_))
+        ));
+    }
+    let short_settings = PageRankSettings {
+        max_iterations: 2,
+        ..settings
+    };
+    assert!(matches!(
+        graph.page_rank(&short_settings),
+        Err(VeloxGraphError::DidNotConverge(2))
+    ));
+}
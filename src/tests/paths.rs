@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// INFO: TEST SIMPLE PATHS AND K SHORTEST PATHS.
+#[test]
+fn test_paths_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        u32,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_paths(graph);
+}
+
+#[test]
+fn test_paths_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        u32, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_paths(graph);
+}
+
+/// Every simple path from `source` to `target` with its cost, by plain depth first search.
+fn brute_force_paths(
+    connections: &[(usize, usize, u32)],
+    source: usize,
+    target: usize,
+) -> Vec<(Vec<usize>, f64)> {
+    fn search(
+        connections: &[(usize, usize, u32)],
+        target: usize,
+        path: &mut Vec<usize>,
+        cost: f64,
+        paths: &mut Vec<(Vec<usize>, f64)>,
+    ) {
+        let node_id = *path.last().unwrap();
+        if node_id == target {
+            paths.push((path.clone(), cost));
+            return;
+        }
+
+        for &(first_node_id, second_node_id, weight) in connections {
+            if first_node_id == node_id && !path.contains(&second_node_id) {
+                path.push(second_node_id);
+                search(connections, target, path, cost + weight as f64, paths);
+                path.pop();
+            }
+        }
+    }
+
+    let mut paths = Vec::new();
+    search(connections, target, &mut vec![source], 0.0, &mut paths);
+
+    paths.sort_by(|first, second| first.0.cmp(&second.0));
+    paths
+}
+
+fn test_paths<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, u32>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, u32>,
+) {
+    // INFO: a small random graph, checked against brute force.
+    let num_nodes = 8;
+    for index in 0..num_nodes {
+        graph.node_create(index as u32);
+    }
+
+    let mut rng = StdRng::seed_from_u64(37);
+    let mut connections = Vec::new();
+    for first_node_id in 0..num_nodes {
+        for second_node_id in 0..num_nodes {
+            if rng.random_bool(0.4) {
+                let weight = rng.random_range(1..20);
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, weight)
+                    .unwrap();
+                connections.push((first_node_id, second_node_id, weight));
+            }
+        }
+    }
+
+    let expected = brute_force_paths(&connections, 0, 7);
+    assert!(expected.len() > 10, "{}", expected.len());
+
+    let mut paths: Vec<Vec<usize>> = graph.simple_paths(0, 7, None).unwrap().collect();
+    paths.sort_unstable();
+    let expected_paths: Vec<Vec<usize>> = expected.iter().map(|(path, _)| path.clone()).collect();
+    assert_eq!(paths, expected_paths);
+
+    for max_length in 0..num_nodes {
+        let mut paths: Vec<Vec<usize>> = graph
+            .simple_paths(0, 7, Some(max_length))
+            .unwrap()
+            .collect();
+        paths.sort_unstable();
+        let expected_bounded: Vec<Vec<usize>> = expected_paths
+            .iter()
+            .filter(|path| path.len() <= max_length + 1)
+            .cloned()
+            .collect();
+        assert_eq!(paths, expected_bounded);
+    }
+
+    // INFO: Yen returns the cheapest paths in order. Compare costs, as ties may be ordered differently.
+    let mut expected_costs: Vec<f64> = expected.iter().map(|(_, cost)| *cost).collect();
+    expected_costs.sort_by(f64::total_cmp);
+
+    let shortest = graph
+        .k_shortest_paths(0, 7, expected.len() + 5, |weight| *weight as f64)
+        .unwrap();
+    assert_eq!(shortest.len(), expected.len());
+    for (path, expected_cost) in shortest.iter().zip(&expected_costs) {
+        assert_eq!(path.cost, *expected_cost);
+        assert!(expected
+            .iter()
+            .any(|(known, cost)| *known == path.node_ids && *cost == path.cost));
+    }
+
+    let first_three = graph
+        .k_shortest_paths(0, 7, 3, |weight| *weight as f64)
+        .unwrap();
+    assert_eq!(first_three.len(), 3);
+    assert_eq!(first_three[..], shortest[..3]);
+
+    // INFO: invalid arguments.
+    assert!(graph.simple_paths(0, 0, None).is_err());
+    assert!(graph.k_shortest_paths(0, 0, 1, |_| 1.0).is_err());
+    assert!(graph.k_shortest_paths(0, 7, 1, |_| -1.0).is_err());
+    assert!(graph.k_shortest_paths(0, 7, 1, |_| f64::NAN).is_err());
+    assert!(graph.k_shortest_paths(0, 7, 1, |_| 0.0).is_ok());
+    assert!(graph.simple_paths(0, 100, None).is_err());
+
+    // INFO: unreachable target.
+    let isolated_node_id = graph.node_create(99);
+    assert_eq!(
+        graph
+            .simple_paths(0, isolated_node_id, None)
+            .unwrap()
+            .count(),
+        0
+    );
+    assert!(graph
+        .k_shortest_paths(0, isolated_node_id, 3, |_| 1.0)
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use crate::algorithms::pregel::VertexProgram;
+use crate::error::VeloxGraphError;
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// INFO: TEST PREGEL SUPERSTEPS.
+#[test]
+fn test_pregel_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        f64,   // NodeT
+        f64,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_pregel(graph);
+}
+
+#[test]
+fn test_pregel_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        f64, // NodeT
+        f64, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_pregel(graph);
+}
+
+/// Single source shortest paths. Node data is the best distance found so far.
+struct ShortestPaths {
+    source_node_id: usize,
+    num_computes: usize,
+}
+
+impl VertexProgram<f64, f64> for ShortestPaths {
+    type Message = f64;
+
+    fn compute(
+        &mut self,
+        superstep: usize,
+        node_id: usize,
+        distance: &mut f64,
+        messages: &[f64],
+    ) -> Option<f64> {
+        self.num_computes += 1;
+        let candidate = match superstep == 0 && node_id == self.source_node_id {
+            true => 0.0,
+            false => messages.iter().copied().reduce(f64::min)?,
+        };
+        if candidate >= *distance {
+            return None;
+        }
+
+        *distance = candidate;
+        Some(candidate)
+    }
+
+    fn message_along(&mut self, distance: &f64, weight: &f64) -> f64 {
+        distance + weight
+    }
+}
+
+/// Keeps sending forever.
+struct Chatter;
+
+impl VertexProgram<f64, f64> for Chatter {
+    type Message = ();
+
+    fn compute(&mut self, _: usize, _: usize, _: &mut f64, _: &[()]) -> Option<()> {
+        Some(())
+    }
+}
+
+fn test_pregel<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, f64, f64>,
+) {
+    assert_eq!(graph.pregel(&mut Chatter, 5).unwrap(), 0);
+
+    // INFO: a random weighted graph, checked against Dijkstra.
+    let num_nodes = 40;
+    for _ in 0..num_nodes {
+        graph.node_create(f64::INFINITY);
+    }
+
+    let mut rng = StdRng::seed_from_u64(45);
+    for first_node_id in 0..num_nodes {
+        for second_node_id in 0..num_nodes {
+            if first_node_id != second_node_id && rng.random_bool(0.08) {
+                let weight = rng.random_range(0.1..5.0);
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, weight)
+                    .unwrap();
+            }
+        }
+    }
+
+    let mut program = ShortestPaths {
+        source_node_id: 0,
+        num_computes: 0,
+    };
+    let num_supersteps = graph.pregel(&mut program, 1000).unwrap();
+    assert!(num_supersteps > 1);
+    // INFO: every node computes on superstep 0, after that only nodes with messages.
+    assert!(program.num_computes >= num_nodes);
+
+    let dag = graph.shortest_path_dag_weighted(0, &|weight: &f64| *weight);
+    for node_id in 0..num_nodes {
+        let distance = graph.node_get(node_id).unwrap().data;
+        let expected = dag.distances[node_id];
+        assert!(distance == expected || (distance - expected).abs() < 1e-9);
+    }
+
+    // INFO: a program that never goes quiet hits the limit.
+    assert!(matches!(
+        graph.pregel(&mut Chatter, 5),
+        Err(VeloxGraphError::DidNotConverge(5))
+    ));
+
+    // INFO: deleted nodes neither compute nor receive.
+    graph.node_delete(0).unwrap();
+    let mut program = ShortestPaths {
+        source_node_id: 0,
+        num_computes: 0,
+    };
+    assert_eq!(graph.pregel(&mut program, 1000).unwrap(), 1);
+    assert_eq!(program.num_computes, num_nodes - 1);
+}
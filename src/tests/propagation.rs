@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// INFO: TEST SIGNAL PROPAGATION.
+#[test]
+fn test_propagation_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        f64,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_propagation(graph);
+}
+
+#[test]
+fn test_propagation_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        f64, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_propagation(graph);
+}
+
+fn test_propagation<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, f64>,
+) {
+    // INFO: a random sparse layer, checked against a dense weight matrix.
+    let num_nodes = 30;
+    for index in 0..num_nodes {
+        graph.node_create(index as u32);
+    }
+
+    let mut rng = StdRng::seed_from_u64(41);
+    let mut weights = vec![vec![0.0; num_nodes]; num_nodes];
+    for (first_node_id, row) in weights.iter_mut().enumerate() {
+        for (second_node_id, weight) in row.iter_mut().enumerate() {
+            if rng.random_bool(0.1) {
+                *weight = rng.random_range(-1.0..1.0);
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, *weight)
+                    .unwrap();
+            }
+        }
+    }
+
+    // INFO: only a few inputs are active, and the last ones are left out entirely.
+    let mut input_activations = vec![0.0; num_nodes - 5];
+    for activation in input_activations.iter_mut() {
+        if rng.random_bool(0.3) {
+            *activation = rng.random_range(0.0..2.0);
+        }
+    }
+
+    let activation_fn = |sum: f64| sum.tanh();
+    let mut expected = vec![0.0; num_nodes];
+    for (second_node_id, expected_activation) in expected.iter_mut().enumerate() {
+        let sum: f64 = input_activations
+            .iter()
+            .zip(&weights)
+            .map(|(activation, row)| activation * row[second_node_id])
+            .sum();
+        *expected_activation = activation_fn(sum);
+    }
+
+    let pushed = graph
+        .propagate(&input_activations, |weight| *weight, activation_fn)
+        .unwrap();
+    let pulled = graph
+        .propagate_pull(&input_activations, |weight| *weight, activation_fn)
+        .unwrap();
+    for node_id in 0..num_nodes {
+        assert!((pushed[node_id] - expected[node_id]).abs() < 1e-12);
+        assert!((pulled[node_id] - expected[node_id]).abs() < 1e-12);
+    }
+
+    // INFO: empty slots stay 0 even if the activation function moves 0.
+    graph.node_delete(3).unwrap();
+    let shifted = graph
+        .propagate(&[], |weight| *weight, |sum| sum + 1.0)
+        .unwrap();
+    assert_eq!(shifted[3], 0.0);
+    assert_eq!(shifted[4], 1.0);
+    let shifted_pull = graph
+        .propagate_pull(&[], |weight| *weight, |sum| sum + 1.0)
+        .unwrap();
+    assert_eq!(shifted, shifted_pull);
+
+    // INFO: more activations than slots is an error.
+    assert!(graph
+        .propagate(&vec![1.0; num_nodes + 1], |weight| *weight, |sum| sum)
+        .is_err());
+    assert!(graph
+        .propagate_pull(&vec![1.0; num_nodes + 1], |weight| *weight, |sum| sum)
+        .is_err());
+}
@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// INFO: TEST ANCESTORS, DESCENDANTS AND REACHABILITY.
+#[test]
+fn test_reachability_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        u32,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_reachability(graph);
+}
+
+#[test]
+fn test_reachability_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        u32, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_reachability(graph);
+}
+
+/// Hop distance between every pair of nodes, `usize::MAX` if not reachable.
+fn brute_force_distances(connections: &[(usize, usize)], num_nodes: usize) -> Vec<Vec<usize>> {
+    let mut distances = vec![vec![usize::MAX; num_nodes]; num_nodes];
+    for (node_id, row) in distances.iter_mut().enumerate() {
+        row[node_id] = 0;
+    }
+    for &(first_node_id, second_node_id) in connections {
+        if first_node_id != second_node_id {
+            distances[first_node_id][second_node_id] = 1;
+        }
+    }
+
+    for middle in 0..num_nodes {
+        for first in 0..num_nodes {
+            for second in 0..num_nodes {
+                let through = distances[first][middle].saturating_add(distances[middle][second]);
+                if through < distances[first][second] {
+                    distances[first][second] = through;
+                }
+            }
+        }
+    }
+
+    distances
+}
+
+fn test_reachability<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, u32>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, u32>,
+) {
+    // INFO: a sparse random graph, checked against all pairs distances.
+    let num_nodes = 12;
+    for index in 0..num_nodes {
+        graph.node_create(index as u32);
+    }
+
+    let mut rng = StdRng::seed_from_u64(37);
+    let mut connections = Vec::new();
+    for first_node_id in 0..num_nodes {
+        for second_node_id in 0..num_nodes {
+            if rng.random_bool(0.12) {
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, 1)
+                    .unwrap();
+                connections.push((first_node_id, second_node_id));
+            }
+        }
+    }
+
+    let distances = brute_force_distances(&connections, num_nodes);
+    for (node_id, node_distances) in distances.iter().enumerate() {
+        for max_depth in [None, Some(0), Some(1), Some(2), Some(3)] {
+            let limit = max_depth.unwrap_or(num_nodes);
+            let expected_descendants: Vec<usize> = (0..num_nodes)
+                .filter(|&other| other != node_id && node_distances[other] <= limit)
+                .collect();
+            let expected_ancestors: Vec<usize> = (0..num_nodes)
+                .filter(|&other| other != node_id && distances[other][node_id] <= limit)
+                .collect();
+
+            assert_eq!(
+                graph.descendants(node_id, max_depth).unwrap(),
+                expected_descendants
+            );
+            assert_eq!(
+                graph.ancestors(node_id, max_depth).unwrap(),
+                expected_ancestors
+            );
+        }
+
+        for (other, &distance) in node_distances.iter().enumerate() {
+            assert_eq!(
+                graph.is_reachable(node_id, other).unwrap(),
+                distance != usize::MAX
+            );
+        }
+    }
+
+    // INFO: deleted nodes are errors and no longer part of any cone.
+    graph.node_delete(0).unwrap();
+    assert!(graph.descendants(0, None).is_err());
+    assert!(graph.is_reachable(0, 1).is_err());
+    for node_id in 1..num_nodes {
+        assert!(!graph.descendants(node_id, None).unwrap().contains(&0));
+    }
+}
@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// INFO: TEST REACHABILITY INDEX.
+#[test]
+fn test_reachability_index_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        u32,   // NodeT
+        u32,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_reachability_index(graph);
+}
+
+#[test]
+fn test_reachability_index_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        u32, // NodeT
+        u32, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_reachability_index(graph);
+}
+
+/// Compare the index against plain search for every pair of used slots.
+fn assert_index_matches_search<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, u32>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    graph: &mut VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, u32>,
+) {
+    let node_ids: Vec<usize> = graph.node_ids().collect();
+    for &first_node_id in &node_ids {
+        for &second_node_id in &node_ids {
+            assert_eq!(
+                graph
+                    .is_reachable_indexed(first_node_id, second_node_id)
+                    .unwrap(),
+                graph.is_reachable(first_node_id, second_node_id).unwrap()
+            );
+        }
+    }
+}
+
+fn test_reachability_index<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, u32>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, u32, u32>,
+) {
+    let num_nodes = 30;
+    for index in 0..num_nodes {
+        graph.node_create(index as u32);
+    }
+
+    assert!(graph.is_reachable_indexed(0, 1).is_err());
+    graph.reachability_index_enable();
+    assert!(graph.reachability_index_enabled());
+
+    // INFO: a sparse random graph, mostly feed forward with a few loops.
+    let mut rng = StdRng::seed_from_u64(38);
+    for first_node_id in 0..num_nodes {
+        for second_node_id in 0..num_nodes {
+            let probability = if first_node_id < second_node_id {
+                0.08
+            } else {
+                0.01
+            };
+            if rng.random_bool(probability) {
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, 1)
+                    .unwrap();
+            }
+        }
+    }
+    graph.reachability_index_build().unwrap();
+    assert_index_matches_search(&mut graph);
+
+    // INFO: the index follows every kind of change.
+    for step in 0..40 {
+        let first_node_id = rng.random_range(0..num_nodes);
+        let second_node_id = rng.random_range(0..num_nodes);
+        if graph.node_ref(first_node_id).is_err() || graph.node_ref(second_node_id).is_err() {
+            continue;
+        }
+
+        match step % 4 {
+            0 | 1 => graph
+                .nodes_connection_set(first_node_id, second_node_id, 1)
+                .unwrap(),
+            2 => graph
+                .nodes_connection_remove(first_node_id, second_node_id)
+                .unwrap(),
+            _ => {
+                let new_node_id = graph.node_create(0);
+                graph
+                    .nodes_connection_set(new_node_id, first_node_id, 1)
+                    .unwrap();
+            }
+        }
+        assert_index_matches_search(&mut graph);
+    }
+
+    graph.node_delete(5).unwrap();
+    assert!(graph.is_reachable_indexed(5, 0).is_err());
+    assert_index_matches_search(&mut graph);
+
+    graph.reachability_index_disable();
+    assert!(graph.is_reachable_indexed(0, 1).is_err());
+}
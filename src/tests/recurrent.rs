@@ -0,0 +1,136 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::neural::recurrent::{RecurrentEngine, StepDirection};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// INFO: TEST RECURRENT STEPPING.
+#[test]
+fn test_recurrent_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize, // NodeIdT
+        f64,   // NodeT
+        f64,   // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_recurrent(graph);
+}
+
+#[test]
+fn test_recurrent_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16, // NodeIdT
+        f64, // NodeT
+        f64, // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_recurrent(graph);
+}
+
+fn test_recurrent<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, f64>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, f64, f64>,
+) {
+    // INFO: a random recurrent network with a bias in every node, checked against a dense
+    // matrix simulation.
+    let num_nodes = 20;
+    let mut rng = StdRng::seed_from_u64(44);
+    let mut biases = Vec::new();
+    for _ in 0..num_nodes {
+        let bias = rng.random_range(-0.5..0.5);
+        graph.node_create(bias);
+        biases.push(bias);
+    }
+
+    let mut weights = vec![vec![0.0; num_nodes]; num_nodes];
+    for (first_node_id, row) in weights.iter_mut().enumerate() {
+        for (second_node_id, weight) in row.iter_mut().enumerate() {
+            if rng.random_bool(0.15) {
+                *weight = rng.random_range(-1.0..1.0);
+                graph
+                    .nodes_connection_set(first_node_id, second_node_id, *weight)
+                    .unwrap();
+            }
+        }
+    }
+
+    let initial_state: Vec<f64> = (0..num_nodes)
+        .map(|_| match rng.random_bool(0.5) {
+            true => rng.random_range(-1.0..1.0),
+            false => 0.0,
+        })
+        .collect();
+    let update = |_: usize, bias: &mut f64, previous: f64, input: f64| {
+        0.5 * previous + (input + *bias).tanh()
+    };
+
+    let num_steps = 10;
+    let traced_node_ids = [0, 7, 19];
+    let mut expected = initial_state.clone();
+    let mut expected_traces = vec![Vec::new(); traced_node_ids.len()];
+    for _ in 0..num_steps {
+        let previous = expected.clone();
+        for (second_node_id, state) in expected.iter_mut().enumerate() {
+            let input: f64 = previous
+                .iter()
+                .zip(&weights)
+                .map(|(source_state, row)| source_state * row[second_node_id])
+                .sum();
+            *state = 0.5 * previous[second_node_id] + (input + biases[second_node_id]).tanh();
+        }
+        for (trace, &node_id) in expected_traces.iter_mut().zip(&traced_node_ids) {
+            trace.push(expected[node_id]);
+        }
+    }
+
+    for direction in [StepDirection::Pull, StepDirection::Push] {
+        let mut engine = RecurrentEngine::new(initial_state.clone(), direction);
+        engine.trace_nodes(&traced_node_ids);
+        engine.run(&mut graph, num_steps, |weight| *weight, update);
+
+        assert_eq!(engine.num_steps(), num_steps);
+        for (state, expected_state) in engine.state().iter().zip(&expected) {
+            assert!((state - expected_state).abs() < 1e-12);
+        }
+        for (trace, expected_trace) in engine.traces().iter().zip(&expected_traces) {
+            assert_eq!(trace.len(), num_steps);
+            for (value, expected_value) in trace.iter().zip(expected_trace) {
+                assert!((value - expected_value).abs() < 1e-12);
+            }
+        }
+    }
+
+    // INFO: the update function may change node data, and clamped states are used next step.
+    let mut engine = RecurrentEngine::new(Vec::new(), StepDirection::Push);
+    engine.state_set(0, 2.0);
+    engine.step(
+        &mut graph,
+        |weight| *weight,
+        |_, count, _, _| {
+            *count += 1.0;
+            0.0
+        },
+    );
+    assert_eq!(graph.node_get(0).unwrap().data, biases[0] + 1.0);
+    assert!(engine.state().iter().all(|&state| state == 0.0));
+
+    // INFO: empty slots stay at 0, and states past the last slot are dropped.
+    graph.node_delete(3).unwrap();
+    let mut engine = RecurrentEngine::new(vec![1.0; num_nodes], StepDirection::Pull);
+    engine.step(&mut graph, |weight| *weight, |_, _, _, _| 1.0);
+    assert_eq!(engine.state()[3], 0.0);
+    assert_eq!(engine.state()[4], 1.0);
+
+    graph.node_delete(num_nodes - 1).unwrap();
+    engine.step(&mut graph, |weight| *weight, |_, _, _, _| 1.0);
+    assert_eq!(engine.state().len(), num_nodes - 1);
+}
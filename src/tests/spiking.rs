@@ -0,0 +1,202 @@
+#![cfg(test)]
+
+use crate::graph::{VeloxGraph, VeloxGraphHash, VeloxGraphVec};
+use crate::neural::spiking::{Spike, SpikeHandler, SpikeSimulator};
+use crate::unsigned_int::UnsignedInt;
+use crate::ConnectionsBackward;
+use crate::ConnectionsForward;
+use crate::Graph;
+
+// INFO: TEST SPIKING SIMULATION.
+#[test]
+fn test_spiking_vec_usize() {
+    let graph: VeloxGraphVec<
+        usize,      // NodeIdT
+        f64,        // NodeT
+        (f64, f64), // ConnectionT
+    > = VeloxGraphVec::new();
+
+    test_spiking(graph);
+}
+
+#[test]
+fn test_spiking_hash_u16() {
+    let graph: VeloxGraphHash<
+        u16,        // NodeIdT
+        f64,        // NodeT
+        (f64, f64), // ConnectionT
+    > = VeloxGraphHash::new();
+
+    test_spiking(graph);
+}
+
+/// Integrate and fire with threshold 1. Connection data is (weight, delay). Records every
+/// plasticity call and applies a simple additive STDP rule.
+struct IntegrateAndFire {
+    plasticity_calls: Vec<(f64, f64)>,
+}
+
+impl SpikeHandler<f64, (f64, f64)> for IntegrateAndFire {
+    fn delay(&mut self, connection_data: &(f64, f64)) -> f64 {
+        connection_data.1
+    }
+
+    fn spike_arrived(
+        &mut self,
+        _time: f64,
+        _node_id: usize,
+        potential: &mut f64,
+        connection_data: &(f64, f64),
+    ) -> bool {
+        *potential += connection_data.0;
+        if *potential >= 1.0 {
+            *potential = 0.0;
+            return true;
+        }
+
+        false
+    }
+
+    fn plasticity(
+        &mut self,
+        connection_data: &mut (f64, f64),
+        pre_spike_time: f64,
+        post_spike_time: f64,
+    ) {
+        self.plasticity_calls
+            .push((pre_spike_time, post_spike_time));
+        match pre_spike_time <= post_spike_time {
+            true => connection_data.0 += 0.01,
+            false => connection_data.0 -= 0.01,
+        }
+    }
+}
+
+fn test_spiking<
+    NodeIdT: UnsignedInt,
+    ConnForwardT: ConnectionsForward<NodeIdT, (f64, f64)>,
+    ConnBackwardT: ConnectionsBackward<NodeIdT>,
+>(
+    mut graph: VeloxGraph<NodeIdT, ConnForwardT, ConnBackwardT, f64, (f64, f64)>,
+) {
+    // INFO: node0 -> node1 -> node2 chain, plus a slow weak connection node0 -> node2.
+    for _ in 0..3 {
+        graph.node_create(0.0);
+    }
+    graph.nodes_connection_set(0, 1, (1.0, 1.0)).unwrap();
+    graph.nodes_connection_set(1, 2, (0.5, 1.5)).unwrap();
+    graph.nodes_connection_set(0, 2, (0.5, 3.0)).unwrap();
+
+    let mut handler = IntegrateAndFire {
+        plasticity_calls: Vec::new(),
+    };
+    let mut simulator = SpikeSimulator::new();
+    simulator.stimulate(0, 0.0).unwrap();
+
+    let spikes = simulator.run_until(&mut graph, 2.0, &mut handler).unwrap();
+    assert_eq!(
+        spikes,
+        vec![
+            Spike {
+                time: 0.0,
+                node_id: 0
+            },
+            Spike {
+                time: 1.0,
+                node_id: 1
+            }
+        ]
+    );
+    assert_eq!(simulator.time(), 2.0);
+    assert_eq!(simulator.pending_events(), 2);
+
+    // INFO: node2 fires once both halves have arrived, at 2.5 and 3.0.
+    let spikes = simulator.run_until(&mut graph, 10.0, &mut handler).unwrap();
+    assert_eq!(
+        spikes,
+        vec![Spike {
+            time: 3.0,
+            node_id: 2
+        }]
+    );
+    assert_eq!(simulator.last_spike_time(0), Some(0.0));
+    assert_eq!(simulator.last_spike_time(2), Some(3.0));
+    assert_eq!(simulator.pending_events(), 0);
+
+    // INFO: causal pairs were potentiated when the targets fired.
+    assert_eq!(
+        handler.plasticity_calls,
+        vec![(0.0, 1.0), (1.0, 3.0), (0.0, 3.0)]
+    );
+    let weight = graph
+        .node_get(0)
+        .unwrap()
+        .connections_forward()
+        .get(1)
+        .unwrap()
+        .data
+        .0;
+    assert!((weight - 1.01).abs() < 1e-12);
+
+    // INFO: node0 firing again after node1 depresses the connection.
+    handler.plasticity_calls.clear();
+    simulator.stimulate(0, 20.0).unwrap();
+    simulator.run_until(&mut graph, 20.0, &mut handler).unwrap();
+    assert_eq!(handler.plasticity_calls, vec![(20.0, 1.0), (20.0, 3.0)]);
+
+    // INFO: spikes in flight over a removed connection are dropped.
+    graph.nodes_connection_remove(0, 1).unwrap();
+    let spikes = simulator.run_until(&mut graph, 30.0, &mut handler).unwrap();
+    assert!(spikes.is_empty());
+
+    // INFO: a self connection makes a node fire periodically. It is paired as an outgoing
+    // connection, so every spike depresses it a little.
+    graph.nodes_connection_set(1, 1, (1.5, 2.0)).unwrap();
+    handler.plasticity_calls.clear();
+    simulator.stimulate(1, 40.0).unwrap();
+    let spikes = simulator.run_until(&mut graph, 44.0, &mut handler).unwrap();
+    let node1_spike_times: Vec<f64> = spikes
+        .iter()
+        .filter(|spike| spike.node_id == 1)
+        .map(|spike| spike.time)
+        .collect();
+    assert_eq!(node1_spike_times, vec![40.0, 42.0, 44.0]);
+    // INFO: the self connection pairs each spike with the previous one once, not twice.
+    for (previous_time, time) in [(40.0, 42.0), (42.0, 44.0)] {
+        assert!(handler.plasticity_calls.contains(&(time, previous_time)));
+        assert!(!handler.plasticity_calls.contains(&(previous_time, time)));
+    }
+    graph.nodes_connection_remove(1, 1).unwrap();
+    simulator
+        .run_until(&mut graph, 100.0, &mut handler)
+        .unwrap();
+
+    // INFO: invalid input.
+    assert!(simulator.stimulate(0, 50.0).is_err());
+    graph.nodes_connection_set(0, 1, (1.0, -1.0)).unwrap();
+    handler.plasticity_calls.clear();
+    simulator.stimulate(0, 100.0).unwrap();
+    assert!(simulator
+        .run_until(&mut graph, 200.0, &mut handler)
+        .is_err());
+
+    // INFO: a bad delay is caught before any of the firing is applied.
+    assert!(handler.plasticity_calls.is_empty());
+    assert_eq!(simulator.last_spike_time(0), Some(20.0));
+    assert_eq!(simulator.pending_events(), 0);
+
+    // INFO: a zero delay is rejected too, since a loop of them would never advance time.
+    graph.nodes_connection_set(0, 1, (1.0, 0.0)).unwrap();
+    simulator.stimulate(0, 150.0).unwrap();
+    assert!(simulator
+        .run_until(&mut graph, 200.0, &mut handler)
+        .is_err());
+
+    // INFO: stimulating a deleted node does nothing.
+    graph.node_delete(2).unwrap();
+    simulator.stimulate(2, 300.0).unwrap();
+    assert!(simulator
+        .run_until(&mut graph, 300.0, &mut handler)
+        .unwrap()
+        .is_empty());
+}